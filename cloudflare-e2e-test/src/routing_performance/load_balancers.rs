@@ -8,19 +8,43 @@ pub async fn test_lb_pool(
     use cloudflare::endpoints::load_balancing::*;
     use std::net::{IpAddr, Ipv4Addr};
 
+    // Create a monitor for the pool
+    let monitor = api_client
+        .request(&monitors::CreateMonitor {
+            account_identifier,
+            params: monitors::MonitorConfig {
+                description: Some("test monitor".to_owned()),
+                interval: Some(60),
+                ..monitors::MonitorConfig::new(monitors::MonitorKind::Http(monitors::HttpMonitor {
+                    path: Some("/health".to_owned()),
+                    expected_codes: Some("2xx".to_owned()),
+                    ..Default::default()
+                }))
+            },
+        })
+        .await
+        .log_err(|e| println!("Error in CreateMonitor: {e}"))?
+        .result;
+
     // Create a pool
     let origins = vec![
         Origin {
             name: "test-origin".to_owned(),
-            address: IpAddr::V4(Ipv4Addr::new(152, 122, 3, 1)),
+            address: IpAddr::V4(Ipv4Addr::new(152, 122, 3, 1)).into(),
             enabled: true,
             weight: 1.0,
+            header: None,
+            port: None,
+            virtual_network_id: None,
         },
         Origin {
             name: "test-origin-2".to_owned(),
-            address: IpAddr::V4(Ipv4Addr::new(152, 122, 3, 2)),
+            address: IpAddr::V4(Ipv4Addr::new(152, 122, 3, 2)).into(),
             enabled: true,
             weight: 1.0,
+            header: None,
+            port: None,
+            virtual_network_id: None,
         },
     ];
     let pool = api_client
@@ -32,7 +56,7 @@ pub async fn test_lb_pool(
                     description: Some("test description"),
                     enabled: Some(true),
                     minimum_origins: Some(2),
-                    monitor: Some("9004c07f1c0f33255410e45590251cf4"),
+                    monitor: Some(&monitor.id),
                     notification_email: Some("test@example.com"),
                 }),
                 origins: &origins,
@@ -60,6 +84,15 @@ pub async fn test_lb_pool(
         .await
         .log_err(|e| println!("Error in DeletePool: {e}"))?;
 
+    // Delete the monitor, now that no pool uses it
+    let _ = api_client
+        .request(&monitors::DeleteMonitor {
+            account_identifier,
+            identifier: &monitor.id,
+        })
+        .await
+        .log_err(|e| println!("Error in DeleteMonitor: {e}"))?;
+
     // Validate the pool we got was the same as the pool we sent
     let pool_details = pool_details?.result;
     assert_eq!(pool, pool_details);
@@ -165,6 +165,7 @@ async fn write_bulk(
                 value: v.into(),
                 expiration: None,
                 expiration_ttl: None,
+                metadata: None,
                 base64: None,
             })
             .collect(),
@@ -410,9 +411,7 @@ pub async fn test_kv(client: &AsyncClient, account_id: &str) -> anyhow::Result<(
             assert_eq!(errors.errors.len(), 1);
             assert_eq!(errors.errors[0].code, 10013);
         }
-        ApiFailure::Invalid(e) => {
-            panic!("Unexpected error: {e}");
-        }
+        e => panic!("Unexpected error: {e}"),
     }
     //endregion
 
@@ -46,6 +46,7 @@ where
                 }
             }
             ApiFailure::Invalid(reqwest_err) => println!("Error: {reqwest_err}"),
+            ApiFailure::BodyAlreadySent => println!("Error: the request body was already sent"),
         },
     }
 }
@@ -71,6 +72,7 @@ where
                 }
             }
             ApiFailure::Invalid(reqwest_err) => println!("Error: {reqwest_err}"),
+            ApiFailure::BodyAlreadySent => println!("Error: the request body was already sent"),
         },
     }
 }
@@ -8,6 +8,10 @@ use crate::framework::{
 };
 
 /// Get an inference from a model.
+///
+/// The result is guessed from the shape of the response, which is ambiguous for some tasks. Prefer
+/// the endpoint of the task in [`run`](super::run), e.g.
+/// [`RunTextGeneration`](super::run::RunTextGeneration), whose result type is known.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ExecuteModel<'a> {
     pub account_identifier: &'a str,
@@ -44,11 +48,7 @@ pub enum ExecuteModelParams {
     /// Text Classification task.
     ///
     /// Classifies the input text into predefined categories.
-    TextClassification {
-        /// The text that you want to classify.
-        /// Must be at least 1 character long.
-        text: String,
-    },
+    TextClassification(TextClassificationParams),
 
     /// Text-to-Image generation task.
     ///
@@ -63,10 +63,7 @@ pub enum ExecuteModelParams {
     /// Text Embedding generation task.
     ///
     /// Converts text into numerical embeddings.
-    TextEmbeddings {
-        /// The array of texts to embed.
-        text: Vec<String>,
-    },
+    TextEmbeddings(TextEmbeddingsParams),
 
     /// Automatic Speech Recognition task.
     ///
@@ -76,18 +73,12 @@ pub enum ExecuteModelParams {
     /// Image Classification task.
     ///
     /// Classifies an image into predefined categories.
-    ImageClassification {
-        /// An array of integers representing the image data (8-bit unsigned integer values).
-        image: Vec<u8>,
-    },
+    ImageClassification(ImageParams),
 
     /// Object Detection task.
     ///
     /// Detects objects in the input image.
-    ObjectDetection {
-        /// An array of integers representing the image data (8-bit unsigned integer values).
-        image: Vec<u8>,
-    },
+    ObjectDetection(ImageParams),
 
     /// General Prompt task.
     ///
@@ -112,7 +103,30 @@ pub enum ExecuteModelParams {
     ImageToText(ImageToTextParams),
 }
 
+/// Parameters for the `TextClassification` task.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TextClassificationParams {
+    /// The text that you want to classify.
+    /// Must be at least 1 character long.
+    pub text: String,
+}
+
+/// Parameters for the `TextEmbeddings` task.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TextEmbeddingsParams {
+    /// The array of texts to embed.
+    pub text: Vec<String>,
+}
+
+/// Parameters for the `ImageClassification` and `ObjectDetection` tasks.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ImageParams {
+    /// An array of integers representing the image data (8-bit unsigned integer values).
+    pub image: Vec<u8>,
+}
+
 /// Parameters for the `TextToImage` task.
+#[serde_with::skip_serializing_none]
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct TextToImageParams {
     /// A text description of the image to generate.
@@ -174,6 +188,14 @@ pub struct AutomaticSpeechRecognitionParams {
     pub target_lang: Option<String>,
 }
 
+/// The input of a text generation model: a prompt, or a conversation.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum TextGenerationInput {
+    Prompt(PromptParams),
+    Messages(MessagesParams),
+}
+
 /// Parameters for the `Prompt` task.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct PromptParams {
@@ -565,7 +587,18 @@ pub struct ResponseAndToolCallsResult {
     /// Array of tool call requests made during the response generation.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub tool_calls: Vec<ToolCall>,
-    // TODO: Missing `usage` field
+
+    /// The number of tokens used by the inference, if the model reports it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
+}
+
+/// The number of tokens used by an inference.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Hash)]
+pub struct Usage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
 }
 
 /// Represents a single tool call request during response generation.
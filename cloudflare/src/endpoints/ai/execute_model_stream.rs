@@ -0,0 +1,393 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::stream::Stream;
+use serde::Deserialize;
+use serde_json::Value;
+
+pub use super::execute_model::Usage;
+use super::execute_model::{TextGenerationInput, ToolCall};
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiErrors, ApiSuccess, ByteStream, ResponseConverter};
+
+/// Get an inference from a text generation model, streamed token by token.
+///
+/// This is the streaming variant of [`ExecuteModel`](super::execute_model::ExecuteModel), for
+/// text generation only: `stream` is set to `true` whatever the params say, and the server-sent
+/// events of the response are parsed into a [`TokenStream`].
+/// <https://developers.cloudflare.com/workers-ai/features/streaming/>
+#[derive(Clone, Debug)]
+pub struct ExecuteModelStream<'a> {
+    pub account_identifier: &'a str,
+    pub model_name: &'a str,
+    pub params: &'a TextGenerationInput,
+}
+
+impl EndpointSpec for ExecuteModelStream<'_> {
+    const IS_STREAMING_BODY: bool = true;
+
+    type JsonResponse = ();
+    type ResponseType = TokenStream;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/ai/run/{}",
+            self.account_identifier, self.model_name
+        )
+    }
+
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let mut body = serde_json::to_value(self.params).unwrap();
+        if let Value::Object(params) = &mut body {
+            params.insert("stream".to_string(), Value::Bool(true));
+        }
+        Some(RequestBody::Json(body.to_string()))
+    }
+}
+
+/// A piece of the generated text, from a single server-sent event.
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+pub struct TokenDelta {
+    /// The text generated since the previous delta. Empty in the final event reporting `usage`.
+    #[serde(default)]
+    pub response: String,
+
+    /// Tool calls requested by the model, if any.
+    #[serde(default)]
+    pub tool_calls: Vec<ToolCall>,
+
+    /// The tokens used by the whole inference, only reported by some models in the last event.
+    pub usage: Option<Usage>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum StreamError {
+    #[error("reading the response failed: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid event {data:?}: {source}")]
+    Invalid {
+        data: String,
+        source: serde_json::Error,
+    },
+    #[error("the inference failed mid-stream: {0:?}")]
+    Api(ApiErrors),
+    #[error("the response ended before the [DONE] event")]
+    Incomplete,
+}
+
+/// The token deltas of a streamed inference, ending after the `[DONE]` event.
+///
+/// An error ends the stream. With the blocking client, use [`TokenStream::into_blocking_iter`]
+/// to consume it.
+pub struct TokenStream {
+    body: ByteStream,
+    buffer: Vec<u8>,
+    /// How much of the buffer was searched for the end of an event.
+    scanned: usize,
+    pending: VecDeque<Result<TokenDelta, StreamError>>,
+    done: bool,
+}
+
+impl TokenStream {
+    fn new(body: ByteStream) -> Self {
+        TokenStream {
+            body,
+            buffer: Vec::new(),
+            scanned: 0,
+            pending: VecDeque::new(),
+            done: false,
+        }
+    }
+
+    /// Iterates over the deltas, blocking the current thread on each of them.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn into_blocking_iter(self) -> impl Iterator<Item = Result<TokenDelta, StreamError>> {
+        futures_executor::block_on_stream(self)
+    }
+
+    /// Parses the complete events of the buffer, leaving a trailing partial event in it.
+    fn parse_events(&mut self) {
+        while !self.done {
+            let Some((end, separator)) = find_event_end(&self.buffer, self.scanned) else {
+                self.scanned = self.buffer.len();
+                return;
+            };
+            self.scanned = 0;
+            let event: Vec<u8> = self.buffer.drain(..end + separator).take(end).collect();
+            self.parse_event(&String::from_utf8_lossy(&event));
+        }
+    }
+
+    fn parse_event(&mut self, event: &str) {
+        let mut name = None;
+        let mut data = Vec::new();
+        for line in event.lines() {
+            if let Some(value) = line.strip_prefix("data:") {
+                data.push(value.strip_prefix(' ').unwrap_or(value));
+            } else if let Some(value) = line.strip_prefix("event:") {
+                name = Some(value.trim());
+            }
+        }
+        // Comments and keep-alives have no data.
+        if data.is_empty() {
+            return;
+        }
+        let data = data.join("\n");
+
+        if data == "[DONE]" {
+            self.done = true;
+            return;
+        }
+        let value: Value = match serde_json::from_str(&data) {
+            Ok(value) => value,
+            Err(_) if name == Some("error") => {
+                let mut errors = ApiErrors::default();
+                errors
+                    .other
+                    .insert("error".to_string(), Value::String(data));
+                return self.fail(StreamError::Api(errors));
+            }
+            Err(source) => return self.fail(StreamError::Invalid { data, source }),
+        };
+        if name == Some("error") || value.get("errors").is_some() {
+            let errors = serde_json::from_value(value.clone()).unwrap_or_else(|_| {
+                let mut errors = ApiErrors::default();
+                errors.other.insert("error".to_string(), value);
+                errors
+            });
+            return self.fail(StreamError::Api(errors));
+        }
+        match serde_json::from_value(value) {
+            Ok(delta) => self.pending.push_back(Ok(delta)),
+            Err(source) => self.fail(StreamError::Invalid { data, source }),
+        }
+    }
+
+    fn fail(&mut self, error: StreamError) {
+        self.pending.push_back(Err(error));
+        self.done = true;
+    }
+}
+
+/// The length of the first event of the buffer, and of the blank line ending it.
+///
+/// The first `scanned` bytes are known not to hold the end of an event, but for a blank line
+/// split across chunks.
+fn find_event_end(buffer: &[u8], scanned: usize) -> Option<(usize, usize)> {
+    // The longest blank line, `\r\n\r\n`, may have started in the last 3 scanned bytes.
+    (scanned.saturating_sub(3)..buffer.len()).find_map(|i| {
+        let rest = &buffer[i..];
+        if rest.starts_with(b"\r\n\r\n") {
+            Some((i, 4))
+        } else if rest.starts_with(b"\n\n") || rest.starts_with(b"\r\r") {
+            Some((i, 2))
+        } else {
+            None
+        }
+    })
+}
+
+impl Stream for TokenStream {
+    type Item = Result<TokenDelta, StreamError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(item) = self.pending.pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+            match Pin::new(&mut self.body).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Some(Ok(chunk))) => {
+                    self.buffer.extend_from_slice(&chunk);
+                    self.parse_events();
+                }
+                Poll::Ready(Some(Err(e))) => self.fail(e.into()),
+                Poll::Ready(None) => {
+                    // The last event may not be followed by a blank line.
+                    let rest = std::mem::take(&mut self.buffer);
+                    self.parse_event(&String::from_utf8_lossy(&rest));
+                    if !self.done {
+                        self.fail(StreamError::Incomplete);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Debug for TokenStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenStream")
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ResponseConverter<()> for TokenStream {
+    fn from_raw(_bytes: Vec<u8>) -> Self {
+        panic!("This endpoint does not return raw bytes")
+    }
+    fn from_json(_api: ApiSuccess<()>) -> Self {
+        panic!("This endpoint does not return JSON")
+    }
+    fn from_stream(stream: ByteStream) -> Self {
+        TokenStream::new(stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::ai::execute_model::PromptParams;
+    use crate::endpoints::ai::run::Model;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use bytes::Bytes;
+    use futures_util::stream::{self, StreamExt};
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn token_stream(chunks: &[&'static str]) -> TokenStream {
+        let chunks: Vec<io::Result<Bytes>> = chunks
+            .iter()
+            .map(|chunk| Ok(Bytes::from_static(chunk.as_bytes())))
+            .collect();
+        TokenStream::new(ByteStream::new(stream::iter(chunks)))
+    }
+
+    #[tokio::test]
+    async fn test_execute_model_stream() {
+        let mut server = Server::new_async().await;
+        let run = server
+            .mock("POST", "/accounts/acc/ai/run/@cf/meta/llama-3.1-8b-instruct")
+            .match_body(Matcher::Json(json!({"prompt": "Hi", "stream": true})))
+            .with_header("content-type", "text/event-stream")
+            .with_body(concat!(
+                "data: {\"response\":\"Hel\",\"p\":\"abc\"}\n\n",
+                ": keep-alive\n\n",
+                "data: {\"response\":\"lo\"}\r\n\r\n",
+                "data: {\"response\":\"\",\"usage\":{\"prompt_tokens\":3,\"completion_tokens\":2,\"total_tokens\":5}}\n\n",
+                "data: [DONE]\n\n",
+            ))
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let deltas: Vec<TokenDelta> = client
+            .request(&ExecuteModelStream {
+                account_identifier: "acc",
+                model_name: Model::LLAMA_3_1_8B_INSTRUCT.name(),
+                params: &TextGenerationInput::Prompt(PromptParams {
+                    prompt: "Hi".to_string(),
+                    ..Default::default()
+                }),
+            })
+            .await
+            .unwrap()
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        run.assert();
+        let text: String = deltas.iter().map(|d| d.response.as_str()).collect();
+        assert_eq!(text, "Hello");
+        assert_eq!(deltas.last().unwrap().usage.unwrap().total_tokens, 5);
+    }
+
+    #[tokio::test]
+    async fn test_events_split_across_chunks_and_errors() {
+        let deltas: Vec<_> = token_stream(&["data: {\"respo", "nse\":\"a\"}\n", "\ndata: [DONE]"])
+            .collect()
+            .await;
+        assert_eq!(deltas.len(), 1);
+        assert_eq!(deltas[0].as_ref().unwrap().response, "a");
+
+        let mut failed = token_stream(&[
+            "data: {\"response\":\"a\"}\n\n",
+            "event: error\ndata: {\"errors\":[{\"code\":3040,\"message\":\"Capacity temporarily exceeded\"}]}\n\n",
+            "data: {\"response\":\"never\"}\n\n",
+        ]);
+        assert!(failed.next().await.unwrap().is_ok());
+        match failed.next().await {
+            Some(Err(StreamError::Api(errors))) => assert_eq!(errors.errors[0].code, 3040),
+            other => panic!("expected an API error, got {other:?}"),
+        }
+        assert!(failed.next().await.is_none());
+
+        let crlf: Vec<_> =
+            token_stream(&["data: {\"response\":\"b\"}\r\n", "\r", "\ndata: [DONE]\n\n"])
+                .collect()
+                .await;
+        assert_eq!(crlf.len(), 1);
+        assert_eq!(crlf[0].as_ref().unwrap().response, "b");
+
+        let truncated: Vec<_> = token_stream(&["data: {\"response\":\"a\"}\n\n"])
+            .collect()
+            .await;
+        assert!(matches!(truncated[1], Err(StreamError::Incomplete)));
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_execute_model_stream_blocking() {
+        use crate::endpoints::ai::execute_model::{Message, MessagesParams};
+        use crate::framework::client::blocking_api::HttpApiClient;
+
+        let mut server = Server::new();
+        let run = server
+            .mock(
+                "POST",
+                "/accounts/acc/ai/run/@cf/meta/llama-3.1-8b-instruct",
+            )
+            .match_body(Matcher::PartialJson(json!({"stream": true})))
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                "data: {\"response\":\"Hi\"}\n\ndata: {\"response\":\"!\"}\n\ndata: [DONE]\n\n",
+            )
+            .create();
+
+        let client = HttpApiClient::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let text: String = client
+            .request(&ExecuteModelStream {
+                account_identifier: "acc",
+                model_name: "@cf/meta/llama-3.1-8b-instruct",
+                params: &TextGenerationInput::Messages(MessagesParams {
+                    messages: vec![Message::user("Hello".to_string())],
+                    ..Default::default()
+                }),
+            })
+            .unwrap()
+            .into_blocking_iter()
+            .map(|delta| delta.unwrap().response)
+            .collect();
+
+        run.assert();
+        assert_eq!(text, "Hi!");
+    }
+}
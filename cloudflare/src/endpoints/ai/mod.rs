@@ -1 +1,3 @@
 pub mod execute_model;
+pub mod execute_model_stream;
+pub mod run;
@@ -0,0 +1,481 @@
+//! Workers AI inference endpoints with a result type known at compile time.
+//!
+//! Each task has its own endpoint, e.g. [`RunTextGeneration`] or [`RunEmbeddings`], and its own
+//! [`Model`]s: a model of one task can't be run with the params of another one. Models missing
+//! from the catalog can be run with [`Model::raw`]. Text generation can also be streamed token by
+//! token with [`ExecuteModelStream`](super::execute_model_stream::ExecuteModelStream), given the
+//! [`Model::name`] of a text generation model.
+//!
+//! ```
+//! use cloudflare::endpoints::ai::run::{Model, RunTextGeneration, TextGenerationInput};
+//! use cloudflare::endpoints::ai::execute_model::PromptParams;
+//!
+//! let params = TextGenerationInput::Prompt(PromptParams {
+//!     prompt: "Tell me a joke".to_string(),
+//!     ..Default::default()
+//! });
+//! let endpoint = RunTextGeneration {
+//!     account_identifier: "account",
+//!     model: &Model::LLAMA_3_1_8B_INSTRUCT,
+//!     params: &params,
+//! };
+//! ```
+//! <https://developers.cloudflare.com/workers-ai/models/>
+
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::Serialize;
+
+pub use super::execute_model::TextGenerationInput;
+
+use super::execute_model::{
+    AudioResult, AutomaticSpeechRecognitionParams, AutomaticSpeechRecognitionResult,
+    ImageClassificationResult, ImageParams, ImageToTextParams, ImageToTextResult,
+    ObjectDetectionResult, ResponseAndToolCallsResult, SummarizationParams, SummarizationResult,
+    TextClassificationParams, TextClassificationResult, TextEmbeddingsParams, TextEmbeddingsResult,
+    TextToImageParams, TextToSpeechParams, TranslationParams, TranslationResult,
+};
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess, ResponseConverter};
+
+/// A Workers AI task: the params its models take and the result they return.
+pub trait Task {
+    /// Whether the models of the task answer with raw bytes rather than JSON.
+    const IS_RAW_BODY: bool = false;
+
+    type Params: Serialize + fmt::Debug;
+    /// The JSON result, `()` for raw tasks.
+    type Output: ApiResult;
+    /// What running a model of the task returns: `ApiSuccess<Self::Output>`, or `Vec<u8>` for raw
+    /// tasks.
+    type Response: ResponseConverter<Self::Output>;
+}
+
+/// A model of the task `T`, by name, e.g. `@cf/meta/llama-3.1-8b-instruct`.
+pub struct Model<T> {
+    name: Cow<'static, str>,
+    task: PhantomData<fn() -> T>,
+}
+
+impl<T: Task> Model<T> {
+    pub const fn new(name: &'static str) -> Self {
+        Model {
+            name: Cow::Borrowed(name),
+            task: PhantomData,
+        }
+    }
+
+    /// A model missing from the catalog, e.g. a new or fine-tuned one. Nothing checks that it
+    /// actually performs the task `T`.
+    pub fn raw(name: impl Into<String>) -> Self {
+        Model {
+            name: Cow::Owned(name.into()),
+            task: PhantomData,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T> Clone for Model<T> {
+    fn clone(&self) -> Self {
+        Model {
+            name: self.name.clone(),
+            task: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Model<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Model").field(&self.name).finish()
+    }
+}
+
+impl<T> fmt::Display for Model<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)
+    }
+}
+
+/// Run a model of the task `T`. Use the alias of the task, e.g. [`RunTextGeneration`].
+/// <https://developers.cloudflare.com/api/resources/ai/methods/run/>
+#[derive(Debug)]
+pub struct Run<'a, T: Task> {
+    pub account_identifier: &'a str,
+    pub model: &'a Model<T>,
+    pub params: &'a T::Params,
+}
+
+impl<T: Task> EndpointSpec for Run<'_, T> {
+    const IS_RAW_BODY: bool = T::IS_RAW_BODY;
+
+    type JsonResponse = T::Output;
+    type ResponseType = T::Response;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+
+    fn path(&self) -> String {
+        format!("accounts/{}/ai/run/{}", self.account_identifier, self.model)
+    }
+
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Generates text from a prompt or a conversation.
+#[derive(Debug)]
+pub enum TextGeneration {}
+
+impl Task for TextGeneration {
+    type Params = TextGenerationInput;
+    type Output = ResponseAndToolCallsResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunTextGeneration<'a> = Run<'a, TextGeneration>;
+
+impl Model<TextGeneration> {
+    pub const LLAMA_3_1_8B_INSTRUCT: Self = Model::new("@cf/meta/llama-3.1-8b-instruct");
+    pub const LLAMA_3_3_70B_INSTRUCT_FP8_FAST: Self =
+        Model::new("@cf/meta/llama-3.3-70b-instruct-fp8-fast");
+    pub const MISTRAL_7B_INSTRUCT_V0_1: Self = Model::new("@cf/mistral/mistral-7b-instruct-v0.1");
+}
+
+/// Converts texts into vectors of numbers, e.g. to search them by similarity.
+#[derive(Debug)]
+pub enum Embeddings {}
+
+impl Task for Embeddings {
+    type Params = TextEmbeddingsParams;
+    type Output = TextEmbeddingsResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunEmbeddings<'a> = Run<'a, Embeddings>;
+
+impl Model<Embeddings> {
+    pub const BGE_SMALL_EN_V1_5: Self = Model::new("@cf/baai/bge-small-en-v1.5");
+    pub const BGE_BASE_EN_V1_5: Self = Model::new("@cf/baai/bge-base-en-v1.5");
+    pub const BGE_LARGE_EN_V1_5: Self = Model::new("@cf/baai/bge-large-en-v1.5");
+}
+
+/// Classifies a text into predefined categories, e.g. a sentiment.
+#[derive(Debug)]
+pub enum TextClassification {}
+
+impl Task for TextClassification {
+    type Params = TextClassificationParams;
+    type Output = Vec<TextClassificationResult>;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunTextClassification<'a> = Run<'a, TextClassification>;
+
+impl Model<TextClassification> {
+    pub const DISTILBERT_SST_2_INT8: Self = Model::new("@cf/huggingface/distilbert-sst-2-int8");
+}
+
+/// Classifies an image into predefined categories.
+#[derive(Debug)]
+pub enum ImageClassification {}
+
+impl Task for ImageClassification {
+    type Params = ImageParams;
+    type Output = Vec<ImageClassificationResult>;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunImageClassification<'a> = Run<'a, ImageClassification>;
+
+impl Model<ImageClassification> {
+    pub const RESNET_50: Self = Model::new("@cf/microsoft/resnet-50");
+}
+
+/// Detects objects in an image.
+#[derive(Debug)]
+pub enum ObjectDetection {}
+
+impl Task for ObjectDetection {
+    type Params = ImageParams;
+    type Output = Vec<ObjectDetectionResult>;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunObjectDetection<'a> = Run<'a, ObjectDetection>;
+
+impl Model<ObjectDetection> {
+    pub const DETR_RESNET_50: Self = Model::new("@cf/facebook/detr-resnet-50");
+}
+
+/// Translates a text into another language.
+#[derive(Debug)]
+pub enum Translation {}
+
+impl Task for Translation {
+    type Params = TranslationParams;
+    type Output = TranslationResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunTranslation<'a> = Run<'a, Translation>;
+
+impl Model<Translation> {
+    pub const M2M100_1_2B: Self = Model::new("@cf/meta/m2m100-1.2b");
+}
+
+/// Summarizes a text.
+#[derive(Debug)]
+pub enum Summarization {}
+
+impl Task for Summarization {
+    type Params = SummarizationParams;
+    type Output = SummarizationResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunSummarization<'a> = Run<'a, Summarization>;
+
+impl Model<Summarization> {
+    pub const BART_LARGE_CNN: Self = Model::new("@cf/facebook/bart-large-cnn");
+}
+
+/// Describes an image.
+#[derive(Debug)]
+pub enum ImageToText {}
+
+impl Task for ImageToText {
+    type Params = ImageToTextParams;
+    type Output = ImageToTextResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunImageToText<'a> = Run<'a, ImageToText>;
+
+impl Model<ImageToText> {
+    pub const LLAVA_1_5_7B_HF: Self = Model::new("@cf/llava-hf/llava-1.5-7b-hf");
+    pub const UFORM_GEN2_QWEN_500M: Self = Model::new("@cf/unum/uform-gen2-qwen-500m");
+}
+
+/// Transcribes audio into text.
+#[derive(Debug)]
+pub enum SpeechRecognition {}
+
+impl Task for SpeechRecognition {
+    type Params = AutomaticSpeechRecognitionParams;
+    type Output = AutomaticSpeechRecognitionResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunSpeechRecognition<'a> = Run<'a, SpeechRecognition>;
+
+impl Model<SpeechRecognition> {
+    pub const WHISPER: Self = Model::new("@cf/openai/whisper");
+    pub const WHISPER_TINY_EN: Self = Model::new("@cf/openai/whisper-tiny-en");
+}
+
+/// Generates an image from a text description.
+///
+/// Only models answering with the raw image, e.g. a PNG, are supported: models answering with
+/// JSON, such as `@cf/black-forest-labs/flux-1-schnell`, can be run with
+/// [`ExecuteModel`](super::execute_model::ExecuteModel).
+#[derive(Debug)]
+pub enum TextToImage {}
+
+impl Task for TextToImage {
+    const IS_RAW_BODY: bool = true;
+
+    type Params = TextToImageParams;
+    type Output = ();
+    type Response = Vec<u8>;
+}
+
+pub type RunTextToImage<'a> = Run<'a, TextToImage>;
+
+impl Model<TextToImage> {
+    pub const STABLE_DIFFUSION_XL_BASE_1_0: Self =
+        Model::new("@cf/stabilityai/stable-diffusion-xl-base-1.0");
+    pub const STABLE_DIFFUSION_XL_LIGHTNING: Self =
+        Model::new("@cf/bytedance/stable-diffusion-xl-lightning");
+    pub const DREAMSHAPER_8_LCM: Self = Model::new("@cf/lykon/dreamshaper-8-lcm");
+}
+
+/// Converts text into speech.
+#[derive(Debug)]
+pub enum TextToSpeech {}
+
+impl Task for TextToSpeech {
+    type Params = TextToSpeechParams;
+    type Output = AudioResult;
+    type Response = ApiSuccess<Self::Output>;
+}
+
+pub type RunTextToSpeech<'a> = Run<'a, TextToSpeech>;
+
+impl Model<TextToSpeech> {
+    pub const MELOTTS: Self = Model::new("@cf/myshell-ai/melotts");
+}
+
+impl ApiResult for ResponseAndToolCallsResult {}
+impl ApiResult for TextEmbeddingsResult {}
+impl ApiResult for Vec<TextClassificationResult> {}
+impl ApiResult for Vec<ImageClassificationResult> {}
+impl ApiResult for Vec<ObjectDetectionResult> {}
+impl ApiResult for TranslationResult {}
+impl ApiResult for SummarizationResult {}
+impl ApiResult for ImageToTextResult {}
+impl ApiResult for AutomaticSpeechRecognitionResult {}
+impl ApiResult for AudioResult {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::ai::execute_model::{Message, MessagesParams};
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_results_are_typed_by_task() {
+        let mut server = Server::new_async().await;
+        // The same response shape for two tasks: an untagged enum can't tell them apart.
+        let labels = json!({"success": true, "errors": [], "messages": [], "result": [
+            {"label": "POSITIVE", "score": 0.98}
+        ]})
+        .to_string();
+        let classify_text = server
+            .mock(
+                "POST",
+                "/accounts/acc/ai/run/@cf/huggingface/distilbert-sst-2-int8",
+            )
+            .match_body(Matcher::Json(json!({"text": "I love it"})))
+            .with_body(&labels)
+            .create();
+        let classify_image = server
+            .mock("POST", "/accounts/acc/ai/run/@cf/custom/image-classifier")
+            .match_body(Matcher::Json(json!({"image": [1, 2, 3]})))
+            .with_body(&labels)
+            .create();
+        let generate = server
+            .mock(
+                "POST",
+                "/accounts/acc/ai/run/@cf/meta/llama-3.1-8b-instruct",
+            )
+            .match_body(Matcher::Json(
+                json!({"messages": [{"role": "user", "content": "Hi"}], "max_tokens": 16}),
+            ))
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": {
+                    "response": "Hello!",
+                    "usage": {"prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15}
+                }})
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+
+        let text: Vec<TextClassificationResult> = client
+            .request(&RunTextClassification {
+                account_identifier: "acc",
+                model: &Model::DISTILBERT_SST_2_INT8,
+                params: &TextClassificationParams {
+                    text: "I love it".to_string(),
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+        classify_text.assert();
+        assert_eq!(text[0].label, "POSITIVE");
+
+        let image: Vec<ImageClassificationResult> = client
+            .request(&RunImageClassification {
+                account_identifier: "acc",
+                model: &Model::raw("@cf/custom/image-classifier"),
+                params: &ImageParams {
+                    image: vec![1, 2, 3],
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+        classify_image.assert();
+        assert_eq!(image[0].label, "POSITIVE");
+
+        let generated = client
+            .request(&RunTextGeneration {
+                account_identifier: "acc",
+                model: &Model::LLAMA_3_1_8B_INSTRUCT,
+                params: &TextGenerationInput::Messages(MessagesParams {
+                    messages: vec![Message::user("Hi".to_string())],
+                    max_tokens: Some(16),
+                    ..Default::default()
+                }),
+            })
+            .await
+            .unwrap()
+            .result;
+        generate.assert();
+        assert_eq!(generated.response, "Hello!");
+        assert_eq!(generated.usage.unwrap().total_tokens, 15);
+    }
+
+    #[tokio::test]
+    async fn test_text_to_image_returns_raw_bytes() {
+        let mut server = Server::new_async().await;
+        let png = [0x89, b'P', b'N', b'G', 0, 1, 2];
+        let generate = server
+            .mock(
+                "POST",
+                "/accounts/acc/ai/run/@cf/stabilityai/stable-diffusion-xl-base-1.0",
+            )
+            .match_body(Matcher::Json(json!({"prompt": "A cat", "num_steps": 10})))
+            .with_header("content-type", "image/png")
+            .with_body(png)
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let image: Vec<u8> = client
+            .request(&RunTextToImage {
+                account_identifier: "acc",
+                model: &Model::STABLE_DIFFUSION_XL_BASE_1_0,
+                params: &TextToImageParams {
+                    prompt: "A cat".to_string(),
+                    num_steps: Some(10),
+                    ..Default::default()
+                },
+            })
+            .await
+            .unwrap();
+
+        generate.assert();
+        assert_eq!(image, png);
+    }
+}
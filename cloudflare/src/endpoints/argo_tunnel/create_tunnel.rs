@@ -5,7 +5,7 @@ use serde_with::{
     serde_as,
 };
 
-use super::Tunnel;
+use crate::endpoints::cfd_tunnel::Tunnel;
 use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
 use crate::framework::response::ApiSuccess;
 
@@ -13,15 +13,17 @@ use crate::framework::response::ApiSuccess;
 /// This creates the Tunnel, which can then be routed and ran. Creating the Tunnel per se is only
 /// a metadata operation (i.e. no Tunnel is running at this point).
 /// <https://api.cloudflare.com/#argo-tunnel-create-argo-tunnel>
+#[deprecated(note = "use `cfd_tunnel::create_tunnel::CreateTunnel`")]
 #[derive(Debug)]
 pub struct CreateTunnel<'a> {
     pub account_identifier: &'a str,
     pub params: Params<'a>,
 }
 
+#[allow(deprecated)]
 impl EndpointSpec for CreateTunnel<'_> {
     type JsonResponse = Tunnel;
-    type ResponseType = ApiSuccess<Tunnel>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
 
     fn method(&self) -> Method {
         Method::POST
@@ -1,59 +1,19 @@
-use chrono::{offset::Utc, DateTime};
-use serde::{Deserialize, Serialize};
-use uuid::Uuid;
+use crate::endpoints::cfd_tunnel;
 
-use crate::framework::response::ApiResult;
+#[deprecated(note = "use `cfd_tunnel::Tunnel`")]
+pub type Tunnel = cfd_tunnel::Tunnel;
 
-/// A Named Argo Tunnel
-/// This is an Argo Tunnel that has been created. It can be used for routing and subsequent running.
-/// <https://api.cloudflare.com/#argo-tunnel-properties>
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-pub struct Tunnel {
-    pub id: Uuid,
-    pub created_at: DateTime<Utc>,
-    pub deleted_at: Option<DateTime<Utc>>,
-    pub name: String,
-    pub connections: Vec<ActiveConnection>,
-    pub metadata: serde_json::Value,
-}
+#[deprecated(note = "use `cfd_tunnel::ActiveConnection`")]
+pub type ActiveConnection = cfd_tunnel::ActiveConnection;
 
-/// An active connection for a Named Argo Tunnel.
-/// <https://api.cloudflare.com/#argo-tunnel-properties>
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-pub struct ActiveConnection {
-    pub colo_name: String,
-    pub id: Uuid,
-    pub is_pending_reconnect: bool,
-}
+#[deprecated(note = "use `cfd_tunnel::RouteResult`")]
+pub type RouteResult = cfd_tunnel::RouteResult;
 
-impl ApiResult for Tunnel {}
-impl ApiResult for Vec<Tunnel> {}
+#[deprecated(note = "use `cfd_tunnel::DnsRouteResult`")]
+pub type DnsRouteResult = cfd_tunnel::DnsRouteResult;
 
-/// The result of a route request for a Named Argo Tunnel
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-#[serde(untagged)]
-pub enum RouteResult {
-    Dns(DnsRouteResult),
-    Lb(LoadBalancerRouteResult),
-}
+#[deprecated(note = "use `cfd_tunnel::LoadBalancerRouteResult`")]
+pub type LoadBalancerRouteResult = cfd_tunnel::LoadBalancerRouteResult;
 
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-pub struct DnsRouteResult {
-    pub cname: Change,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-pub struct LoadBalancerRouteResult {
-    pub load_balancer: Change,
-    pub pool: Change,
-}
-
-#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
-pub enum Change {
-    Unchanged,
-    New,
-    Updated,
-}
-
-impl ApiResult for RouteResult {}
+#[deprecated(note = "use `cfd_tunnel::Change`")]
+pub type Change = cfd_tunnel::Change;
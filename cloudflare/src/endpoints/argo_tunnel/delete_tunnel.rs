@@ -1,9 +1,10 @@
-use super::Tunnel;
+use crate::endpoints::cfd_tunnel::Tunnel;
 use crate::framework::endpoint::{EndpointSpec, Method};
 use crate::framework::response::ApiSuccess;
 
 /// Delete a tunnel
 /// <https://api.cloudflare.com/#argo-tunnel-delete-argo-tunnel>
+#[deprecated(note = "use `cfd_tunnel::delete_tunnel::DeleteTunnel`")]
 #[derive(Debug)]
 pub struct DeleteTunnel<'a> {
     pub account_identifier: &'a str,
@@ -12,6 +13,7 @@ pub struct DeleteTunnel<'a> {
     pub cascade: bool,
 }
 
+#[allow(deprecated)]
 impl EndpointSpec for DeleteTunnel<'_> {
     type JsonResponse = Tunnel;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
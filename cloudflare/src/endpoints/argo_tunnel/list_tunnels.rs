@@ -1,18 +1,20 @@
 use chrono::{DateTime, Utc};
 use serde::Serialize;
 
-use super::Tunnel;
+use crate::endpoints::cfd_tunnel::Tunnel;
 use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
 use crate::framework::response::ApiSuccess;
 
 /// List/search tunnels in an account.
 /// <https://api.cloudflare.com/#argo-tunnel-list-argo-tunnels>
+#[deprecated(note = "use `cfd_tunnel::list_tunnels::ListAllTunnels`")]
 #[derive(Debug)]
 pub struct ListTunnels<'a> {
     pub account_identifier: &'a str,
     pub params: Params,
 }
 
+#[allow(deprecated)]
 impl EndpointSpec for ListTunnels<'_> {
     type JsonResponse = Vec<Tunnel>;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
@@ -1,7 +1,18 @@
+//! The legacy Argo Tunnel API, superseded by [`cfd_tunnel`](crate::endpoints::cfd_tunnel).
+//!
+//! Its types are now aliases of the `cfd_tunnel` ones, and its endpoints return
+//! [`cfd_tunnel::Tunnel`](crate::endpoints::cfd_tunnel::Tunnel). To migrate:
+//! - `argo_tunnel::create_tunnel::CreateTunnel` → `cfd_tunnel::create_tunnel::CreateTunnel`
+//! - `argo_tunnel::list_tunnels::ListTunnels` → `cfd_tunnel::list_tunnels::ListAllTunnels`, or
+//!   `cfd_tunnel::list_tunnels::ListTunnels` for Cfd Tunnels only
+//! - `argo_tunnel::delete_tunnel::DeleteTunnel` → `cfd_tunnel::delete_tunnel::DeleteTunnel`
+//! - `argo_tunnel::route_dns::RouteTunnel` → `cfd_tunnel::route_dns::RouteTunnel`
+
 pub mod create_tunnel;
 mod data_structures;
 pub mod delete_tunnel;
 pub mod list_tunnels;
 pub mod route_dns;
 
+#[allow(deprecated)]
 pub use data_structures::*;
@@ -0,0 +1,316 @@
+use crate::endpoints::cfd_tunnel::ConfigurationSrc;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// The configuration of a tunnel managed remotely (i.e. with `config_src` set to `cloudflare`),
+/// which `cloudflared` fetches instead of reading a local YAML file.
+/// <https://developers.cloudflare.com/cloudflare-one/connections/connect-networks/configure-tunnels/local-management/configuration-file/>
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TunnelConfiguration {
+    /// The rules routing each request to a service, evaluated in order. The last rule must be a
+    /// catch-all rule, without hostname nor path.
+    #[serde(default)]
+    pub ingress: Vec<IngressRule>,
+    /// The default origin request settings of every ingress rule.
+    #[serde(rename = "originRequest")]
+    pub origin_request: Option<OriginRequest>,
+    /// Whether the tunnel routes private network traffic from WARP clients.
+    #[serde(rename = "warp-routing")]
+    pub warp_routing: Option<WarpRouting>,
+}
+
+impl TunnelConfiguration {
+    /// Checks the ingress rules the way `cloudflared` does before accepting them.
+    pub fn validate(&self) -> Result<(), IngressError> {
+        let Some((last, rules)) = self.ingress.split_last() else {
+            return Err(IngressError::Empty);
+        };
+        for (index, rule) in self.ingress.iter().enumerate() {
+            if rule.service.is_empty() {
+                return Err(IngressError::EmptyService(index));
+            }
+            if let Some(hostname) = &rule.hostname {
+                let wildcards = hostname.matches('*').count();
+                if wildcards > 1 || (wildcards == 1 && !hostname.starts_with("*.")) {
+                    return Err(IngressError::InvalidHostname {
+                        index,
+                        hostname: hostname.clone(),
+                    });
+                }
+            }
+        }
+        if !last.is_catch_all() {
+            return Err(IngressError::NoCatchAll);
+        }
+        if let Some(index) = rules.iter().position(IngressRule::is_catch_all) {
+            return Err(IngressError::CatchAllNotLast(index));
+        }
+        Ok(())
+    }
+}
+
+/// A reason ingress rules were rejected by [`TunnelConfiguration::validate`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum IngressError {
+    #[error("there must be at least one ingress rule")]
+    Empty,
+    #[error("the last ingress rule must match every request: it can't have a hostname or path")]
+    NoCatchAll,
+    #[error("ingress rule #{0} matches every request, so the rules after it are never used")]
+    CatchAllNotLast(usize),
+    #[error("ingress rule #{0} has no service")]
+    EmptyService(usize),
+    #[error("ingress rule #{index} has an invalid hostname {hostname:?}: a wildcard can only be the first label")]
+    InvalidHostname { index: usize, hostname: String },
+}
+
+/// A rule routing the requests matching its hostname and path to a service.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct IngressRule {
+    /// The hostname to match, e.g. `app.example.com` or `*.example.com`.
+    pub hostname: Option<String>,
+    /// A regular expression the request path must match, e.g. `^/api/`.
+    pub path: Option<String>,
+    /// Where to send the matching requests, e.g. `http://localhost:8000`, `ssh://localhost:22`,
+    /// `unix:/run/app.sock` or `http_status:404`.
+    pub service: String,
+    /// Overrides the default origin request settings for this rule.
+    #[serde(rename = "originRequest")]
+    pub origin_request: Option<OriginRequest>,
+}
+
+impl IngressRule {
+    /// A rule sending every request for `hostname` to `service`.
+    pub fn new(hostname: impl Into<String>, service: impl Into<String>) -> Self {
+        IngressRule {
+            hostname: Some(hostname.into()),
+            path: None,
+            service: service.into(),
+            origin_request: None,
+        }
+    }
+
+    /// A rule matching every request, to put last.
+    pub fn catch_all(service: impl Into<String>) -> Self {
+        IngressRule {
+            hostname: None,
+            path: None,
+            service: service.into(),
+            origin_request: None,
+        }
+    }
+
+    pub fn is_catch_all(&self) -> bool {
+        self.hostname.is_none() && self.path.is_none()
+    }
+}
+
+/// How `cloudflared` connects to the origin service.
+/// <https://developers.cloudflare.com/cloudflare-one/connections/connect-networks/configure-tunnels/origin-configuration/>
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct OriginRequest {
+    /// Timeout for establishing a new TCP connection to the origin, in seconds.
+    pub connect_timeout: Option<u32>,
+    /// Timeout for completing a TLS handshake with the origin, in seconds.
+    pub tls_timeout: Option<u32>,
+    /// Timeout after which an idle keepalive connection can be discarded, in seconds.
+    pub keep_alive_timeout: Option<u32>,
+    /// Maximum number of idle keepalive connections between the tunnel and the origin.
+    pub keep_alive_connections: Option<u32>,
+    /// Timeout after which a TCP keepalive packet is sent on an idle connection, in seconds.
+    pub tcp_keep_alive: Option<u32>,
+    /// Disables the "happy eyeballs" algorithm for IPv4/IPv6 fallback.
+    pub no_happy_eyeballs: Option<bool>,
+    /// Disables chunked transfer encoding, for WSGI servers that don't support it.
+    pub disable_chunked_encoding: Option<bool>,
+    /// Sets the HTTP `Host` header sent to the origin.
+    pub http_host_header: Option<String>,
+    /// The hostname the origin certificate is expected to be for.
+    pub origin_server_name: Option<String>,
+    /// Uses the request's hostname as the SNI of the TLS connection to the origin.
+    #[serde(rename = "matchSNItoHost")]
+    pub match_sni_to_host: Option<bool>,
+    /// Path to the CA certificate of the origin, on the host running `cloudflared`.
+    pub ca_pool: Option<String>,
+    /// Disables TLS verification of the origin certificate.
+    #[serde(rename = "noTLSVerify")]
+    pub no_tls_verify: Option<bool>,
+    /// Connects to the origin with HTTP/2 instead of HTTP/1.1.
+    #[serde(rename = "http2Origin")]
+    pub http2_origin: Option<bool>,
+    /// `socks` to run a SOCKS5 proxy. Empty for a regular proxy.
+    pub proxy_type: Option<String>,
+    /// Requires a valid Access JWT on the requests.
+    pub access: Option<AccessSettings>,
+}
+
+/// Validation of the Access JWT of the requests.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessSettings {
+    /// Whether requests without a valid Access JWT are rejected.
+    pub required: Option<bool>,
+    /// The Zero Trust team name, e.g. `example` for `example.cloudflareaccess.com`.
+    pub team_name: Option<String>,
+    /// The Application Audience (AUD) tags the JWT must be for.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aud_tag: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WarpRouting {
+    pub enabled: bool,
+}
+
+/// A version of the remote configuration of a tunnel.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct TunnelConfigurationResult {
+    pub account_id: Option<String>,
+    pub tunnel_id: Option<Uuid>,
+    /// Incremented each time the configuration is updated.
+    pub version: Option<u64>,
+    pub config: Option<TunnelConfiguration>,
+    pub source: Option<ConfigurationSrc>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+impl ApiResult for TunnelConfigurationResult {}
+
+/// Get the remote configuration of a Cfd Tunnel
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/subresources/configurations/methods/get/>
+#[derive(Debug)]
+pub struct GetTunnelConfiguration<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+}
+
+impl EndpointSpec for GetTunnelConfiguration<'_> {
+    type JsonResponse = TunnelConfigurationResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}/configurations",
+            self.account_identifier, self.tunnel_id
+        )
+    }
+}
+
+/// Replace the remote configuration of a Cfd Tunnel
+/// Running `cloudflared` instances pick up the new configuration. Use
+/// [`TunnelConfiguration::validate`] to check it first.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/subresources/configurations/methods/update/>
+#[derive(Debug)]
+pub struct UpdateTunnelConfiguration<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+    pub config: &'a TunnelConfiguration,
+}
+
+impl EndpointSpec for UpdateTunnelConfiguration<'_> {
+    type JsonResponse = TunnelConfigurationResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}/configurations",
+            self.account_identifier, self.tunnel_id
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            config: &'a TunnelConfiguration,
+        }
+
+        let body = serde_json::to_string(&Body {
+            config: self.config,
+        })
+        .unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ingress_serialization_and_validation() {
+        let mut api = IngressRule::new("api.example.com", "https://localhost:8443");
+        api.path = Some("^/v1/".into());
+        api.origin_request = Some(OriginRequest {
+            no_tls_verify: Some(true),
+            http2_origin: Some(true),
+            access: Some(AccessSettings {
+                required: Some(true),
+                team_name: Some("example".into()),
+                aud_tag: vec!["aud".into()],
+            }),
+            ..Default::default()
+        });
+        let mut config = TunnelConfiguration {
+            ingress: vec![api, IngressRule::catch_all("http_status:404")],
+            origin_request: Some(OriginRequest {
+                connect_timeout: Some(10),
+                ..Default::default()
+            }),
+            warp_routing: Some(WarpRouting { enabled: true }),
+        };
+        assert_eq!(config.validate(), Ok(()));
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({
+                "ingress": [
+                    {
+                        "hostname": "api.example.com",
+                        "path": "^/v1/",
+                        "service": "https://localhost:8443",
+                        "originRequest": {
+                            "noTLSVerify": true,
+                            "http2Origin": true,
+                            "access": {"required": true, "teamName": "example", "audTag": ["aud"]}
+                        }
+                    },
+                    {"service": "http_status:404"}
+                ],
+                "originRequest": {"connectTimeout": 10},
+                "warp-routing": {"enabled": true}
+            })
+        );
+
+        config.ingress.swap(0, 1);
+        assert_eq!(config.validate(), Err(IngressError::NoCatchAll));
+        config
+            .ingress
+            .insert(0, IngressRule::catch_all("hello_world"));
+        config.ingress.pop();
+        assert_eq!(config.validate(), Err(IngressError::CatchAllNotLast(0)));
+        config.ingress = vec![
+            IngressRule::new("app.*.example.com", "http://localhost"),
+            IngressRule::catch_all("http_status:404"),
+        ];
+        assert!(matches!(
+            config.validate(),
+            Err(IngressError::InvalidHostname { index: 0, .. })
+        ));
+    }
+}
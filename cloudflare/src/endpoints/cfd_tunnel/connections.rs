@@ -0,0 +1,209 @@
+use super::TunnelConnector;
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+use serde::Serialize;
+
+/// List the connectors of a tunnel, i.e. the `cloudflared` instances running it, with their
+/// connections.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/subresources/connections/methods/get/>
+#[derive(Debug)]
+pub struct ListTunnelConnections<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+}
+
+impl EndpointSpec for ListTunnelConnections<'_> {
+    type JsonResponse = Vec<TunnelConnector>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}/connections",
+            self.account_identifier, self.tunnel_id
+        )
+    }
+}
+
+/// Get a single connector of a tunnel.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/subresources/connectors/methods/get/>
+#[derive(Debug)]
+pub struct TunnelConnectorDetails<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+    /// The [`TunnelConnector::id`], i.e. the `client_id` of its connections.
+    pub client_id: &'a str,
+}
+
+impl EndpointSpec for TunnelConnectorDetails<'_> {
+    type JsonResponse = TunnelConnector;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}/connectors/{}",
+            self.account_identifier, self.tunnel_id, self.client_id
+        )
+    }
+}
+
+/// Clean up tunnel connections
+/// Removes the connections of connectors that are no longer running, e.g. after a host was
+/// replaced. Running connectors reconnect.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/subresources/connections/methods/delete/>
+#[derive(Debug)]
+pub struct CleanupTunnelConnections<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+    pub params: CleanupParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CleanupParams<'a> {
+    /// Only clean up the connections of this connector. All the connectors otherwise.
+    pub client_id: Option<&'a str>,
+}
+
+impl EndpointSpec for CleanupTunnelConnections<'_> {
+    type JsonResponse = serde_json::Value;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}/connections",
+            self.account_identifier, self.tunnel_id
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::cfd_tunnel::tunnel_details::TunnelDetails;
+    use crate::endpoints::cfd_tunnel::TunnelStatusType;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    const TUNNEL: &str = "f70ff985-a4ef-4643-bbbc-4a0ed4fc8415";
+    const CLIENT: &str = "1bedc50d-42b3-473c-b108-ff3d10c0d925";
+
+    #[tokio::test]
+    async fn test_connectors_and_cleanup() {
+        let conn = json!({
+            "colo_name": "DFW",
+            "uuid": "c13b7a5b-3b1c-4a4c-a7c4-5f4f0b5e2e3a",
+            "id": "c13b7a5b-3b1c-4a4c-a7c4-5f4f0b5e2e3a",
+            "is_pending_reconnect": false,
+            "origin_ip": "192.0.2.10",
+            "opened_at": "2024-03-01T00:00:00Z",
+            "client_id": CLIENT,
+            "client_version": "2024.3.0"
+        });
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", format!("/accounts/acc/cfd_tunnel/{TUNNEL}").as_str())
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": {
+                    "id": TUNNEL,
+                    "account_tag": "acc",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "deleted_at": null,
+                    "name": "web",
+                    "connections": [conn],
+                    "conns_active_at": "2024-03-01T00:00:00Z",
+                    "conns_inactive_at": null,
+                    "tun_type": "cfd_tunnel",
+                    "metadata": {},
+                    "status": "healthy",
+                    "remote_config": true
+                }})
+                .to_string(),
+            )
+            .create();
+        server
+            .mock(
+                "GET",
+                format!("/accounts/acc/cfd_tunnel/{TUNNEL}/connections").as_str(),
+            )
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": [{
+                    "id": CLIENT,
+                    "arch": "linux_amd64",
+                    "config_version": 3,
+                    "conns": [conn],
+                    "features": ["ha-origin"],
+                    "run_at": "2024-03-01T00:00:00Z",
+                    "version": "2024.3.0"
+                }]})
+                .to_string(),
+            )
+            .create();
+        let cleanup = server
+            .mock(
+                "DELETE",
+                format!("/accounts/acc/cfd_tunnel/{TUNNEL}/connections").as_str(),
+            )
+            .match_query(Matcher::UrlEncoded("client_id".into(), CLIENT.into()))
+            .with_body(r#"{"success": true, "errors": [], "messages": [], "result": {}}"#)
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let tunnel = client
+            .request(&TunnelDetails {
+                account_identifier: "acc",
+                tunnel_id: TUNNEL,
+            })
+            .await
+            .unwrap()
+            .result;
+        assert_eq!(tunnel.status, TunnelStatusType::Healthy);
+        assert_eq!(tunnel.remote_config, Some(true));
+
+        let connectors = client
+            .request(&ListTunnelConnections {
+                account_identifier: "acc",
+                tunnel_id: TUNNEL,
+            })
+            .await
+            .unwrap()
+            .result;
+        assert_eq!(connectors[0].id.to_string(), CLIENT);
+        assert_eq!(connectors[0].conns, tunnel.connections);
+
+        client
+            .request(&CleanupTunnelConnections {
+                account_identifier: "acc",
+                tunnel_id: TUNNEL,
+                params: CleanupParams {
+                    client_id: Some(CLIENT),
+                },
+            })
+            .await
+            .unwrap();
+        cleanup.assert();
+    }
+}
@@ -36,7 +36,7 @@ impl EndpointSpec for CreateTunnel<'_> {
     }
 }
 
-/// Params for creating a Named Argo Tunnel
+/// Params for creating a Cfd Tunnel
 #[serde_as]
 #[serde_with::skip_serializing_none]
 #[derive(Serialize, Clone, Debug)]
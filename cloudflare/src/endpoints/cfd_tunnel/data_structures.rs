@@ -13,7 +13,13 @@ pub struct Tunnel {
     pub created_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
     pub name: String,
+    /// Whether this is a Cfd Tunnel run by `cloudflared` or a WARP connector. Responses of the
+    /// legacy Argo Tunnel API don't include it: they only have Cfd Tunnels.
+    #[serde(default)]
+    pub tun_type: TunnelType,
+    #[serde(default)]
     pub connections: Vec<ActiveConnection>,
+    #[serde(default)]
     pub metadata: serde_json::Value,
 }
 
@@ -46,6 +52,37 @@ pub enum TunnelStatusType {
     Healthy,  // Tunnel is healthy
 }
 
+/// The kind of a tunnel. The `teamnet` routes point to any kind of tunnel.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize, Clone, Copy, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TunnelType {
+    #[default]
+    CfdTunnel,
+    WarpConnector,
+    Warp,
+    Magic,
+    IpSec,
+    Gre,
+    Cni,
+    #[serde(other)]
+    Unknown,
+}
+
+impl TunnelType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TunnelType::CfdTunnel => "cfd_tunnel",
+            TunnelType::WarpConnector => "warp_connector",
+            TunnelType::Warp => "warp",
+            TunnelType::Magic => "magic",
+            TunnelType::IpSec => "ip_sec",
+            TunnelType::Gre => "gre",
+            TunnelType::Cni => "cni",
+            TunnelType::Unknown => "unknown",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 pub enum ConfigurationSrc {
     #[serde(rename = "local")]
@@ -55,23 +92,48 @@ pub enum ConfigurationSrc {
     Cloudflare,
 }
 /// An active connection for a Cfd Tunnel
+///
+/// Connections of legacy Argo Tunnels only have a `colo_name`, an `id` and
+/// `is_pending_reconnect`.
+#[serde_with::skip_serializing_none]
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone, Hash)]
 pub struct ActiveConnection {
     pub colo_name: String,
     /// Deprecated, use `id` instead.
-    pub uuid: Uuid,
+    pub uuid: Option<Uuid>,
     pub id: Uuid,
     pub is_pending_reconnect: bool,
-    pub origin_ip: IpAddr,
-    pub opened_at: DateTime<Utc>,
-    pub client_id: Uuid,
-    pub client_version: String,
+    pub origin_ip: Option<IpAddr>,
+    pub opened_at: Option<DateTime<Utc>>,
+    pub client_id: Option<Uuid>,
+    pub client_version: Option<String>,
+}
+
+/// A `cloudflared` instance running a Cfd Tunnel, and its connections to the Cloudflare edge.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Clone)]
+pub struct TunnelConnector {
+    /// The client ID of the connector, also found in the `client_id` of its connections.
+    pub id: Uuid,
+    /// The OS and architecture of the host, e.g. `linux_amd64`.
+    pub arch: Option<String>,
+    /// The version of the remote configuration the connector runs with.
+    pub config_version: Option<u64>,
+    pub conns: Vec<ActiveConnection>,
+    #[serde(default)]
+    pub features: Vec<String>,
+    pub run_at: Option<DateTime<Utc>>,
+    /// The `cloudflared` version, e.g. `2024.3.0`.
+    pub version: Option<String>,
 }
 
 impl ApiResult for Tunnel {}
 impl ApiResult for Vec<Tunnel> {}
+impl ApiResult for TunnelWithConnections {}
+impl ApiResult for TunnelConnector {}
+impl ApiResult for Vec<TunnelConnector> {}
 
-/// The result of a route request for a Cfd Tunnel
+/// The result of a route request for a tunnel
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(untagged)]
 pub enum RouteResult {
@@ -1,6 +1,6 @@
-use crate::endpoints::cfd_tunnel::Tunnel;
+use crate::endpoints::cfd_tunnel::{Tunnel, TunnelType};
 use chrono::{DateTime, Utc};
-use serde::Serialize;
+use serde::{Serialize, Serializer};
 
 use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
 use crate::framework::response::ApiSuccess;
@@ -29,6 +29,49 @@ impl EndpointSpec for ListTunnels<'_> {
     }
 }
 
+/// List/search the tunnels of every type in an account, e.g. both Cfd Tunnels and WARP
+/// connectors. Replaces the legacy `argo_tunnel::list_tunnels::ListTunnels`.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/methods/list/>
+#[derive(Debug)]
+pub struct ListAllTunnels<'a> {
+    pub account_identifier: &'a str,
+    pub params: AllTunnelsParams<'a>,
+}
+
+impl EndpointSpec for ListAllTunnels<'_> {
+    type JsonResponse = Vec<Tunnel>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/tunnels", self.account_identifier)
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+/// Params for filtering tunnels of every type
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct AllTunnelsParams<'a> {
+    /// Only tunnels of these types. Every type if empty.
+    #[serde(
+        skip_serializing_if = "<[_]>::is_empty",
+        serialize_with = "comma_separated"
+    )]
+    pub tun_types: &'a [TunnelType],
+    #[serde(flatten)]
+    pub params: Params,
+}
+
+fn comma_separated<S: Serializer>(types: &&[TunnelType], serializer: S) -> Result<S::Ok, S::Error> {
+    let types: Vec<&str> = types.iter().map(TunnelType::as_str).collect();
+    serializer.serialize_str(&types.join(","))
+}
+
 /// Params for filtering listed tunnels
 #[serde_with::skip_serializing_none]
 #[derive(Serialize, Clone, Debug, Default)]
@@ -50,3 +93,96 @@ pub struct PaginationParams {
     pub page: u64,
     pub per_page: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_list_all_tunnel_types() {
+        let mut server = Server::new_async().await;
+        let list = server
+            .mock("GET", "/accounts/acc/tunnels")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("tun_types".into(), "cfd_tunnel,warp_connector".into()),
+                Matcher::UrlEncoded("is_deleted".into(), "false".into()),
+            ]))
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": [
+                    {
+                        "id": "f70ff985-a4ef-4643-bbbc-4a0ed4fc8415",
+                        "created_at": "2024-01-01T00:00:00Z",
+                        "deleted_at": null,
+                        "name": "web",
+                        "tun_type": "cfd_tunnel",
+                        "connections": [],
+                        "metadata": {}
+                    },
+                    {
+                        "id": "1bedc50d-42b3-473c-b108-ff3d10c0d925",
+                        "created_at": "2024-01-01T00:00:00Z",
+                        "deleted_at": null,
+                        "name": "office",
+                        "tun_type": "warp_connector",
+                        "connections": []
+                    },
+                    {
+                        "id": "c13b7a5b-3b1c-4a4c-a7c4-5f4f0b5e2e3a",
+                        "created_at": "2020-01-01T00:00:00Z",
+                        "deleted_at": null,
+                        "name": "legacy",
+                        "connections": [{
+                            "colo_name": "DFW",
+                            "id": "5f4a3b7e-9d1c-4e2a-8b6f-0c1d2e3f4a5b",
+                            "is_pending_reconnect": false
+                        }]
+                    }
+                ]})
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let tunnels = client
+            .request(&ListAllTunnels {
+                account_identifier: "acc",
+                params: AllTunnelsParams {
+                    tun_types: &[TunnelType::CfdTunnel, TunnelType::WarpConnector],
+                    params: Params {
+                        is_deleted: Some(false),
+                        ..Default::default()
+                    },
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+
+        list.assert();
+        let types: Vec<TunnelType> = tunnels.iter().map(|t| t.tun_type).collect();
+        assert_eq!(
+            types,
+            [
+                TunnelType::CfdTunnel,
+                TunnelType::WarpConnector,
+                TunnelType::CfdTunnel
+            ]
+        );
+        let legacy = &tunnels[2].connections[0];
+        assert_eq!(legacy.colo_name, "DFW");
+        assert_eq!((legacy.client_id, legacy.opened_at), (None, None));
+    }
+}
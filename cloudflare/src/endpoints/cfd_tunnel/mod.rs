@@ -1,8 +1,12 @@
+pub mod configuration;
+pub mod connections;
 pub mod create_tunnel;
 mod data_structures;
 pub mod delete_tunnel;
 pub mod list_tunnels;
 pub mod route_dns;
+pub mod token;
+pub mod tunnel_details;
 pub mod update_tunnel;
 
 pub use data_structures::*;
@@ -1,5 +1,3 @@
-// TODO: Exact same code as in argo_tunnel/route_dns.rs. Consider refactoring?
-
 use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
 
 use super::RouteResult;
@@ -7,7 +5,7 @@ use crate::framework::response::ApiSuccess;
 use serde::Serialize;
 use uuid::Uuid;
 
-/// Route for a Named Argo Tunnel
+/// Route for a Cfd Tunnel
 /// This creates a new route for the identified Tunnel. More than 1 route may co-exist for the same
 /// Tunnel.
 /// Note that this modifies only metadata on Cloudflare side to route traffic to the Tunnel, but
@@ -36,7 +34,7 @@ impl EndpointSpec for RouteTunnel<'_> {
     }
 }
 
-/// Params for routing a Named Argo Tunnel
+/// Params for routing a Cfd Tunnel
 #[derive(Serialize, Clone, Debug)]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum Params<'a> {
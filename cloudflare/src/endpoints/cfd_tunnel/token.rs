@@ -0,0 +1,26 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Get the token used to run a tunnel, i.e. `cloudflared tunnel run --token <TOKEN>`.
+/// The token contains the tunnel secret: keep it as safe as the tunnel credentials file.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/subresources/token/methods/get/>
+#[derive(Debug)]
+pub struct GetTunnelToken<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+}
+
+impl EndpointSpec for GetTunnelToken<'_> {
+    type JsonResponse = String;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}/token",
+            self.account_identifier, self.tunnel_id
+        )
+    }
+}
@@ -0,0 +1,26 @@
+use super::TunnelWithConnections;
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Get a tunnel, with its status and active connections.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/methods/get/>
+#[derive(Debug)]
+pub struct TunnelDetails<'a> {
+    pub account_identifier: &'a str,
+    pub tunnel_id: &'a str,
+}
+
+impl EndpointSpec for TunnelDetails<'_> {
+    type JsonResponse = TunnelWithConnections;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/cfd_tunnel/{}",
+            self.account_identifier, self.tunnel_id
+        )
+    }
+}
@@ -9,10 +9,9 @@ use serde_with::{
 use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
 use crate::framework::response::ApiSuccess;
 
-/// Create a Cfd Tunnel
-/// This creates the Tunnel, which can then be routed and ran. Creating the Tunnel per se is only
-/// a metadata operation (i.e. no Tunnel is running at this point).
-/// <https://developers.cloudflare.com/api/operations/cloudflare-tunnel-create-a-cloudflare-tunnel>
+/// Update a Cfd Tunnel
+/// Renames the Tunnel and/or rotates its secret. Only the given fields are changed.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/tunnels/subresources/cloudflared/methods/edit/>
 #[derive(Debug)]
 pub struct UpdateTunnel<'a> {
     pub account_identifier: &'a str,
@@ -43,15 +42,42 @@ impl EndpointSpec for UpdateTunnel<'_> {
 /// Params for updating a Cfd Tunnel
 #[serde_as]
 #[serde_with::skip_serializing_none]
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Debug, Default)]
 pub struct Params<'a> {
     /// The new name for the Tunnel
-    pub name: &'a str,
-    /// The byte array (with 32 or more bytes) representing a secret for the tunnel. This is
-    /// encoded into JSON as a base64 String. This secret is necessary to run the tunnel.
-    #[serde_as(as = "Base64<Standard, Padded>")]
-    pub tunnel_secret: &'a Vec<u8>,
+    pub name: Option<&'a str>,
+    /// A new secret for the tunnel: a byte array with 32 or more bytes, encoded into JSON as a
+    /// base64 String. Running `cloudflared` instances need the new secret to reconnect.
+    #[serde_as(as = "Option<Base64<Standard, Padded>>")]
+    pub tunnel_secret: Option<&'a Vec<u8>>,
 
     /// Arbitrary metadata for the tunnel.
     pub metadata: Option<serde_json::Value>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_without_secret() {
+        let rename = Params {
+            name: Some("renamed"),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&rename).unwrap(),
+            r#"{"name":"renamed"}"#
+        );
+
+        let secret = vec![0; 32];
+        let rotate = Params {
+            tunnel_secret: Some(&secret),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&rotate).unwrap(),
+            r#"{"tunnel_secret":"AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA="}"#
+        );
+    }
+}
@@ -0,0 +1,195 @@
+use crate::endpoints::load_balancing::{Origin, Pool};
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::offset::Utc;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+/// A change in the health of a pool or of its origins.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct HealthEvent {
+    pub id: u64,
+    pub timestamp: DateTime<Utc>,
+    pub pool: PoolHealthEvent,
+    /// The state of each origin of the pool at the time of the event.
+    #[serde(default)]
+    pub origins: Vec<OriginHealthEvent>,
+}
+
+impl HealthEvent {
+    /// The origins whose health changed with this event.
+    pub fn changed_origins(&self) -> impl Iterator<Item = &OriginHealthEvent> {
+        self.origins.iter().filter(|o| o.changed)
+    }
+
+    /// Pairs the origins of the event with their current configuration in `pool`, by name.
+    /// Origins since removed from the pool are paired with `None`.
+    ///
+    /// Returns nothing when the event is about another pool.
+    pub fn join<'a>(&'a self, pool: &'a Pool) -> Vec<(&'a OriginHealthEvent, Option<&'a Origin>)> {
+        if self.pool.id != pool.id {
+            return Vec::new();
+        }
+        self.origins
+            .iter()
+            .map(|event| (event, pool.origins.iter().find(|o| o.name == event.name)))
+            .collect()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct PoolHealthEvent {
+    /// The [`Pool::id`] of the pool.
+    pub id: String,
+    pub name: Option<String>,
+    pub healthy: bool,
+    /// Whether the pool's health changed with this event, e.g. because it failed over.
+    #[serde(default)]
+    pub changed: bool,
+    pub minimum_origins: Option<u8>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct OriginHealthEvent {
+    /// The [`Origin::name`] of the origin.
+    pub name: String,
+    pub address: Option<String>,
+    /// The IP address the origin's address resolved to.
+    pub ip: Option<String>,
+    pub enabled: Option<bool>,
+    pub healthy: bool,
+    /// Whether the origin's health changed with this event.
+    #[serde(default)]
+    pub changed: bool,
+    /// Why the health check failed, for unhealthy origins.
+    pub failure_reason: Option<String>,
+}
+
+impl ApiResult for Vec<HealthEvent> {}
+
+/// List Healthcheck Events
+/// Lists the changes in the health of the pools and origins of the user's load balancers, newest
+/// first.
+/// <https://developers.cloudflare.com/api/resources/user/subresources/load_balancing_analytics/subresources/events/methods/list/>
+#[derive(Debug)]
+pub struct ListHealthEvents<'a> {
+    pub params: ListHealthEventsParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ListHealthEventsParams<'a> {
+    /// Only events since this time.
+    pub since: Option<DateTime<Utc>>,
+    /// Only events until this time.
+    pub until: Option<DateTime<Utc>>,
+    pub pool_id: Option<&'a str>,
+    pub pool_name: Option<&'a str>,
+    /// Only events where the pool ended up healthy (`true`) or unhealthy (`false`).
+    pub pool_healthy: Option<bool>,
+    pub origin_name: Option<&'a str>,
+    /// Only events where the origin ended up healthy (`true`) or unhealthy (`false`).
+    pub origin_healthy: Option<bool>,
+    /// The maximum number of events to return.
+    pub limit: Option<u32>,
+}
+
+impl EndpointSpec for ListHealthEvents<'_> {
+    type JsonResponse = Vec<HealthEvent>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        "user/load_balancing_analytics/events".to_string()
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_list_pool_failover_events() {
+        let mut server = Server::new_async().await;
+        let events = server
+            .mock("GET", "/user/load_balancing_analytics/events")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("pool_id".into(), "pool".into()),
+                Matcher::UrlEncoded("since".into(), "2024-03-01T02:00:00Z".into()),
+                Matcher::UrlEncoded("pool_healthy".into(), "false".into()),
+            ]))
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": [{
+                    "id": 42,
+                    "timestamp": "2024-03-01T03:00:12Z",
+                    "pool": {"id": "pool", "name": "primary", "healthy": false, "changed": true, "minimum_origins": 1},
+                    "origins": [
+                        {"name": "web", "address": "web.example.com", "ip": "192.0.2.1", "enabled": true,
+                         "healthy": false, "changed": true, "failure_reason": "HTTP timeout occurred"},
+                        {"name": "gone", "address": "192.0.2.9", "healthy": false}
+                    ]
+                }]})
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let result = client
+            .request(&ListHealthEvents {
+                params: ListHealthEventsParams {
+                    pool_id: Some("pool"),
+                    since: Some("2024-03-01T02:00:00Z".parse().unwrap()),
+                    pool_healthy: Some(false),
+                    ..Default::default()
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+
+        events.assert();
+        let event = &result[0];
+        assert!(event.pool.changed && !event.pool.healthy);
+        let changed: Vec<&str> = event.changed_origins().map(|o| o.name.as_str()).collect();
+        assert_eq!(changed, ["web"]);
+
+        let pool: Pool = serde_json::from_value(json!({
+            "id": "pool",
+            "created_on": "2024-01-01T00:00:00Z",
+            "modified_on": "2024-01-01T00:00:00Z",
+            "description": "",
+            "name": "primary",
+            "enabled": true,
+            "minimum_origins": 1,
+            "monitor": "monitor",
+            "check_regions": null,
+            "notification_email": "",
+            "origins": [{"name": "web", "address": "web.example.com", "enabled": true, "weight": 1.0}]
+        }))
+        .unwrap();
+        let joined = event.join(&pool);
+        assert_eq!(joined[0].1.map(|o| o.weight), Some(1.0));
+        assert!(joined[1].1.is_none());
+    }
+}
@@ -1,6 +1,6 @@
 use crate::endpoints::load_balancing::{
-    LbPoolId, LbPoolMapping, LoadBalancer, SessionAffinity, SessionAffinityAttributes,
-    SteeringPolicy,
+    AdaptiveRouting, LbPoolId, LbPoolMapping, LoadBalancer, LoadBalancerNetwork, LoadBalancerRule,
+    LocationStrategy, RandomSteering, SessionAffinity, SessionAffinityAttributes, SteeringPolicy,
 };
 use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
 
@@ -49,11 +49,20 @@ pub struct OptionalParams<'a> {
     /// for the given region. Any regions not explicitly defined will fall back to using
     /// default_pools.
     pub region_pools: Option<LbPoolMapping>,
+    /// A mapping of country codes to a list of pool IDs (ordered by their failover priority) for
+    /// the given country.
+    pub country_pools: Option<LbPoolMapping>,
+    pub enabled: Option<bool>,
     pub proxied: Option<bool>,
     pub steering_policy: Option<SteeringPolicy>,
     pub session_affinity: Option<SessionAffinity>,
     pub session_affinity_attributes: Option<SessionAffinityAttributes>,
     pub session_affinity_ttl: Option<u32>,
+    pub adaptive_routing: Option<AdaptiveRouting>,
+    pub location_strategy: Option<LocationStrategy>,
+    pub random_steering: Option<RandomSteering>,
+    pub rules: Option<&'a [LoadBalancerRule]>,
+    pub networks: Option<&'a [LoadBalancerNetwork]>,
 }
 
 impl EndpointSpec for CreateLoadBalancer<'_> {
@@ -0,0 +1,28 @@
+use crate::endpoints::load_balancing::LoadBalancer;
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Load Balancer Details
+/// <https://developers.cloudflare.com/api/resources/load_balancers/methods/get/>
+#[derive(Debug)]
+pub struct LoadBalancerDetails<'a> {
+    /// The Zone to which this Load Balancer belongs.
+    pub zone_identifier: &'a str,
+    /// Which load balancer to retrieve the details of.
+    pub identifier: &'a str,
+}
+
+impl EndpointSpec for LoadBalancerDetails<'_> {
+    type JsonResponse = LoadBalancer;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/load_balancers/{}",
+            self.zone_identifier, self.identifier
+        )
+    }
+}
@@ -0,0 +1,38 @@
+use crate::endpoints::load_balancing::Pool;
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::Serialize;
+
+/// List Pools
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/pools/methods/list/>
+#[derive(Debug)]
+pub struct ListPools<'a> {
+    /// The Cloudflare account to list Pools from.
+    pub account_identifier: &'a str,
+    pub params: Params<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct Params<'a> {
+    /// Only list the pools using this monitor.
+    pub monitor: Option<&'a str>,
+}
+
+impl EndpointSpec for ListPools<'_> {
+    type JsonResponse = Vec<Pool>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/load_balancers/pools", self.account_identifier)
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+impl ApiResult for Vec<Pool> {}
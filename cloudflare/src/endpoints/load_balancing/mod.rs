@@ -1,15 +1,28 @@
+pub mod analytics;
 pub mod create_lb;
 pub mod create_pool;
 pub mod delete_lb;
 pub mod delete_pool;
+pub mod lb_details;
 pub mod list_lb;
+pub mod list_pools;
+pub mod monitors;
+pub mod origins;
+pub mod patch_lb;
+pub mod patch_pool;
 pub mod pool_details;
+pub mod pool_health;
+pub mod regions;
+pub mod rules;
+pub mod update_lb;
+pub mod update_pool;
 
 use crate::framework::response::ApiResult;
 use chrono::offset::Utc;
 use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::hash::{Hash, Hasher};
 use std::net::IpAddr;
 
@@ -41,12 +54,28 @@ pub struct LoadBalancer {
     /// priority) for the PoP (datacenter). Any PoPs not explicitly defined will fall back to using
     /// default_pools.
     pub pop_pools: LbPoolMapping,
+    /// A mapping of country codes to a list of pool IDs (ordered by their failover priority) for
+    /// the given country. Any country not explicitly defined will fall back to using the
+    /// corresponding region_pool mapping if it exists, else to default_pools.
+    #[serde(default)]
+    pub country_pools: LbPoolMapping,
     pub proxied: bool,
     pub steering_policy: SteeringPolicy,
     pub session_affinity: SessionAffinity,
     pub session_affinity_attributes: SessionAffinityAttributes,
     #[serde(default = "LoadBalancer::default_session_affinity_ttl")]
     pub session_affinity_ttl: u32,
+    pub adaptive_routing: Option<AdaptiveRouting>,
+    pub location_strategy: Option<LocationStrategy>,
+    pub random_steering: Option<RandomSteering>,
+    /// Rules evaluated in order of priority for each request, which can override the load
+    /// balancer's settings.
+    #[serde(default)]
+    pub rules: Vec<LoadBalancerRule>,
+    /// The networks the load balancer serves traffic on.
+    #[serde(default)]
+    pub networks: Vec<LoadBalancerNetwork>,
+    pub zone_name: Option<String>,
 }
 
 impl LoadBalancer {
@@ -62,15 +91,173 @@ type LbPoolId = String;
 type LbPoolMapping = HashMap<String, Vec<LbPoolId>>;
 
 #[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum SteeringPolicy {
     /// Empty policy maps to `Geo` if `region_pools` or `pop_pools` are used, or otherwise `Off`.
     #[serde(rename = "")]
     Nil,
+    /// Use `default_pools`.
     Off,
+    /// Use `region_pools`, `country_pools` and `pop_pools`.
     Geo,
+    /// Select a pool randomly, according to `random_steering`.
     Random,
+    /// Use round trip time to select the closest pool in `default_pools`.
     DynamicLatency,
+    /// Use the pools' latitude and longitude to select the closest pool, according to
+    /// `location_strategy`.
+    Proximity,
+    /// Select a pool by taking into consideration `random_steering` weights, as well as each
+    /// pool's number of outstanding requests.
+    LeastOutstandingRequests,
+    /// Select a pool by taking into consideration `random_steering` weights, as well as each
+    /// pool's number of open connections.
+    LeastConnections,
+}
+
+/// Controls features that modify the routing of requests to pools and origins in response to
+/// dynamic conditions, such as during the interval between active health monitoring requests.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug, Default)]
+pub struct AdaptiveRouting {
+    /// Extends zero-downtime failover of requests to healthy origins from alternate pools, when no
+    /// healthy alternate exists in the same pool.
+    pub failover_across_pools: Option<bool>,
+}
+
+/// Controls location-based steering for non-proxied requests.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug, Default)]
+pub struct LocationStrategy {
+    pub mode: Option<LocationStrategyMode>,
+    /// Whether the EDNS Client Subnet option is used, when the resolver sends it.
+    pub prefer_ecs: Option<PreferEcs>,
+}
+
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum LocationStrategyMode {
+    /// Use the location of the Cloudflare PoP the request arrived at.
+    Pop,
+    /// Use the location of the DNS resolver's IP address.
+    ResolverIp,
+}
+
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum PreferEcs {
+    Always,
+    Never,
+    /// Only for the `Proximity` steering policy.
+    Proximity,
+    /// Only for the `Geo` steering policy.
+    Geo,
+}
+
+/// The weights of the pools for the `Random`, `LeastOutstandingRequests` and `LeastConnections`
+/// steering policies.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Clone, Debug, Default)]
+pub struct RandomSteering {
+    /// The weight of the pools missing from `pool_weights`.
+    pub default_weight: Option<f64>,
+    /// A mapping of pool IDs to their weight, between 0 and 1.
+    #[serde(default)]
+    pub pool_weights: HashMap<LbPoolId, f64>,
+}
+
+// Weights are compared bit for bit, so that a NaN weight equals itself and `Eq` holds.
+impl PartialEq for RandomSteering {
+    fn eq(&self, other: &Self) -> bool {
+        self.default_weight.map(f64::to_bits) == other.default_weight.map(f64::to_bits)
+            && self.pool_weights.len() == other.pool_weights.len()
+            && self.pool_weights.iter().all(|(pool, weight)| {
+                other.pool_weights.get(pool).map(|w| w.to_bits()) == Some(weight.to_bits())
+            })
+    }
+}
+
+impl Eq for RandomSteering {}
+
+/// A rule evaluated for each request, which can override the load balancer's settings.
+/// Build conditions with [`rules::RuleCondition`].
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct LoadBalancerRule {
+    pub name: String,
+    /// The expression the request must match, in the Rules language. Empty for every request.
+    #[serde(default)]
+    pub condition: String,
+    #[serde(default)]
+    pub disabled: bool,
+    /// Rules are evaluated in increasing order of priority.
+    #[serde(default)]
+    pub priority: u32,
+    /// Whether no further rules are evaluated after this one matches. Always the case with a
+    /// `fixed_response`.
+    #[serde(default)]
+    pub terminates: bool,
+    /// The load balancer settings overridden when the rule matches.
+    #[serde(default)]
+    pub overrides: LoadBalancerRuleOverrides,
+    /// A response sent instead of routing the request to a pool, when the rule matches.
+    pub fixed_response: Option<FixedResponse>,
+}
+
+impl LoadBalancerRule {
+    /// An enabled rule overriding nothing yet.
+    pub fn new(name: impl Into<String>, condition: impl Into<String>) -> Self {
+        LoadBalancerRule {
+            name: name.into(),
+            condition: condition.into(),
+            disabled: false,
+            priority: 0,
+            terminates: false,
+            overrides: LoadBalancerRuleOverrides::default(),
+            fixed_response: None,
+        }
+    }
+}
+
+/// The load balancer settings a [`LoadBalancerRule`] overrides. Settings left to `None` are kept.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug, Default)]
+pub struct LoadBalancerRuleOverrides {
+    pub default_pools: Option<Vec<LbPoolId>>,
+    pub fallback_pool: Option<LbPoolId>,
+    pub region_pools: Option<LbPoolMapping>,
+    pub pop_pools: Option<LbPoolMapping>,
+    pub country_pools: Option<LbPoolMapping>,
+    pub steering_policy: Option<SteeringPolicy>,
+    pub random_steering: Option<RandomSteering>,
+    pub adaptive_routing: Option<AdaptiveRouting>,
+    pub location_strategy: Option<LocationStrategy>,
+    pub session_affinity: Option<SessionAffinity>,
+    pub session_affinity_attributes: Option<SessionAffinityAttributes>,
+    pub session_affinity_ttl: Option<u32>,
+    pub ttl: Option<u32>,
+}
+
+/// A response the load balancer sends itself, e.g. a maintenance page or a redirect.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug, Default)]
+pub struct FixedResponse {
+    /// Defaults to 200.
+    pub status_code: Option<u16>,
+    pub content_type: Option<String>,
+    /// The response body, up to 1024 bytes.
+    pub message_body: Option<String>,
+    /// The `Location` header, for redirects.
+    pub location: Option<String>,
+}
+
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LoadBalancerNetwork {
+    /// Traffic from the Internet.
+    Public,
+    /// Traffic from Cloudflare One, e.g. WARP clients.
+    Private,
 }
 
 #[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
@@ -151,6 +338,7 @@ pub struct Pool {
 /// An origin represents something that can serve user requests. Usually a machine, maybe an ELB.
 /// Origins with similar latency functions (e.g. origins in the same data center or region) might be
 /// in the same pool.
+#[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct Origin {
     /// A human-identifiable name for the origin.
@@ -160,7 +348,7 @@ pub struct Origin {
     /// Hostnames entered here should resolve directly to the origin, and not be a hostname proxied
     /// by Cloudflare.
     /// e.g. 0.0.0.0
-    pub address: IpAddr,
+    pub address: OriginAddress,
     /// Whether to enable (the default) this origin within the Pool. Disabled origins will not
     /// receive traffic and are excluded from health checks. The origin will only be disabled for
     /// the current pool.
@@ -168,6 +356,46 @@ pub struct Origin {
     /// The weight of this origin relative to other origins in the Pool. Based on the configured
     /// weight the total traffic is distributed among origins within the Pool.
     pub weight: f64,
+    /// The request headers to send to the origin, e.g. a `Host` header when `address` is an IP
+    /// address. Only `Host` is supported.
+    pub header: Option<HashMap<String, Vec<String>>>,
+    /// The port to send traffic to. Defaults to the port of the request.
+    pub port: Option<u16>,
+    /// The virtual network of a private `address`, for origins reached through a tunnel.
+    pub virtual_network_id: Option<String>,
+}
+
+/// The address of an origin: an IP address, or a hostname resolving to the origin.
+#[derive(Eq, PartialEq, Hash, Deserialize, Serialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum OriginAddress {
+    Ip(IpAddr),
+    Hostname(String),
+}
+
+impl From<IpAddr> for OriginAddress {
+    fn from(ip: IpAddr) -> Self {
+        OriginAddress::Ip(ip)
+    }
+}
+
+impl From<&str> for OriginAddress {
+    /// Parses IP addresses, and takes anything else as a hostname.
+    fn from(address: &str) -> Self {
+        match address.parse() {
+            Ok(ip) => OriginAddress::Ip(ip),
+            Err(_) => OriginAddress::Hostname(address.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for OriginAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OriginAddress::Ip(ip) => ip.fmt(f),
+            OriginAddress::Hostname(hostname) => f.write_str(hostname),
+        }
+    }
 }
 
 // f64 doesn't impl Eq or Hash, so we need some custom implementations.
@@ -178,6 +406,9 @@ impl PartialEq for Origin {
             && self.address == other.address
             && self.enabled == other.enabled
             && diff_is_small
+            && self.header == other.header
+            && self.port == other.port
+            && self.virtual_network_id == other.virtual_network_id
     }
 }
 impl Eq for Origin {}
@@ -188,8 +419,21 @@ impl Hash for Origin {
         self.address.hash(state);
         self.enabled.hash(state);
         self.weight.to_bits().hash(state);
+        self.port.hash(state);
+        self.virtual_network_id.hash(state);
     }
 }
 
+/// The result of the latest health check of an origin.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct OriginHealth {
+    pub healthy: Option<bool>,
+    pub failure_reason: Option<String>,
+    pub response_code: Option<u16>,
+    /// The round trip time of the health check, e.g. `66ms`.
+    pub rtt: Option<String>,
+}
+
 impl ApiResult for Origin {}
 impl ApiResult for Pool {}
@@ -0,0 +1,422 @@
+use crate::endpoints::load_balancing::OriginHealth;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::offset::Utc;
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A monitor issues health checks at regular intervals to evaluate the health of the origins of
+/// the pools it is attached to.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct Monitor {
+    pub id: String,
+    pub created_on: Option<DateTime<Utc>>,
+    pub modified_on: Option<DateTime<Utc>>,
+    #[serde(flatten)]
+    pub config: MonitorConfig,
+}
+
+/// The settings of a monitor.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct MonitorConfig {
+    /// The protocol of the health checks, and its settings.
+    #[serde(flatten)]
+    pub kind: MonitorKind,
+    /// A human-readable description of the monitor.
+    pub description: Option<String>,
+    /// The interval between each health check, in seconds. Defaults to 60.
+    pub interval: Option<u32>,
+    /// The number of retries to attempt in case of a timeout before marking the origin as
+    /// unhealthy. Retries are attempted immediately. Defaults to 2.
+    pub retries: Option<u32>,
+    /// The timeout (in seconds) before marking the health check as failed. Defaults to 5.
+    pub timeout: Option<u32>,
+    /// The number of consecutive successful health checks required before marking an origin as
+    /// healthy. Defaults to 0.
+    pub consecutive_up: Option<u32>,
+    /// The number of consecutive failed health checks required before marking an origin as
+    /// unhealthy. Defaults to 0.
+    pub consecutive_down: Option<u32>,
+}
+
+impl MonitorConfig {
+    /// A monitor using the given protocol, with the default intervals and thresholds.
+    pub fn new(kind: MonitorKind) -> Self {
+        MonitorConfig {
+            kind,
+            description: None,
+            interval: None,
+            retries: None,
+            timeout: None,
+            consecutive_up: None,
+            consecutive_down: None,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MonitorKind {
+    Http(HttpMonitor),
+    Https(HttpMonitor),
+    /// Checks that a TCP connection can be established.
+    Tcp(PortMonitor),
+    /// Sends a UDP packet and expects no ICMP port unreachable error in return.
+    UdpIcmp(PortMonitor),
+    /// Sends an ICMP echo request.
+    IcmpPing,
+    /// Checks that the origin greets SMTP clients.
+    Smtp(PortMonitor),
+}
+
+/// The settings of an HTTP or HTTPS monitor.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug, Default)]
+pub struct HttpMonitor {
+    /// The method of the health check requests. Defaults to `GET`.
+    pub method: Option<String>,
+    /// The endpoint path to health check against. Defaults to `/`.
+    pub path: Option<String>,
+    /// The port to connect to. Defaults to 80 for HTTP and 443 for HTTPS.
+    pub port: Option<u16>,
+    /// The request headers to send in the health check, e.g. a `Host` header.
+    pub header: Option<HashMap<String, Vec<String>>>,
+    /// The expected response codes, e.g. `2xx` or `200`.
+    pub expected_codes: Option<String>,
+    /// A case-insensitive sub-string to look for in the response body. If this string is not
+    /// found, the origin will be marked as unhealthy.
+    pub expected_body: Option<String>,
+    /// Whether to follow redirects while checking the origin.
+    pub follow_redirects: Option<bool>,
+    /// Whether to skip validating the origin's certificate, for HTTPS monitors.
+    pub allow_insecure: Option<bool>,
+    /// The hostname to use for health checks, for origins whose address is an IP address. It
+    /// must be a subdomain of the zone the monitor's pools are used in.
+    pub probe_zone: Option<String>,
+}
+
+/// The settings of a monitor that only connects to a port.
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug, Default)]
+pub struct PortMonitor {
+    pub port: Option<u16>,
+}
+
+/// The pools a monitor preview checks. Get the result with [`PreviewResultDetails`].
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct MonitorPreview {
+    /// Use this to get the result of the preview.
+    pub preview_id: String,
+    /// A mapping of the IDs of the previewed pools to their name.
+    #[serde(default)]
+    pub pools: HashMap<String, String>,
+}
+
+/// The health of the origins of each previewed pool, by pool ID.
+pub type PreviewResult = HashMap<String, PoolPreview>;
+
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct PoolPreview {
+    pub healthy: Option<bool>,
+    /// The health of each origin of the pool, by origin address.
+    #[serde(default)]
+    pub origins: Vec<HashMap<String, OriginHealth>>,
+}
+
+/// A resource that a monitor references, or that references it.
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct MonitorReference {
+    /// `referrer` for a resource referencing the monitor, such as a pool.
+    pub reference_type: String,
+    pub resource_id: String,
+    pub resource_name: String,
+    /// e.g. `pool`
+    pub resource_type: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct DeletedMonitor {
+    pub id: String,
+}
+
+impl ApiResult for Monitor {}
+impl ApiResult for Vec<Monitor> {}
+impl ApiResult for MonitorPreview {}
+impl ApiResult for PreviewResult {}
+impl ApiResult for Vec<MonitorReference> {}
+impl ApiResult for DeletedMonitor {}
+
+/// Create Monitor
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/methods/create/>
+#[derive(Debug)]
+pub struct CreateMonitor<'a> {
+    /// The Cloudflare account to create this Monitor under.
+    pub account_identifier: &'a str,
+    pub params: MonitorConfig,
+}
+
+impl EndpointSpec for CreateMonitor<'_> {
+    type JsonResponse = Monitor;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// List Monitors
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/methods/list/>
+#[derive(Debug)]
+pub struct ListMonitors<'a> {
+    /// The Cloudflare account to list Monitors from.
+    pub account_identifier: &'a str,
+}
+
+impl EndpointSpec for ListMonitors<'_> {
+    type JsonResponse = Vec<Monitor>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors",
+            self.account_identifier
+        )
+    }
+}
+
+/// Monitor Details
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/methods/get/>
+#[derive(Debug)]
+pub struct MonitorDetails<'a> {
+    /// The Cloudflare account of this monitor.
+    pub account_identifier: &'a str,
+    /// Which monitor to retrieve the details of.
+    pub identifier: &'a str,
+}
+
+impl EndpointSpec for MonitorDetails<'_> {
+    type JsonResponse = Monitor;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors/{}",
+            self.account_identifier, self.identifier
+        )
+    }
+}
+
+/// Update Monitor
+/// Replaces the whole configuration of a monitor.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/methods/update/>
+#[derive(Debug)]
+pub struct UpdateMonitor<'a> {
+    /// The Cloudflare account of this monitor.
+    pub account_identifier: &'a str,
+    /// Which monitor to update.
+    pub identifier: &'a str,
+    pub params: MonitorConfig,
+}
+
+impl EndpointSpec for UpdateMonitor<'_> {
+    type JsonResponse = Monitor;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors/{}",
+            self.account_identifier, self.identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Delete Monitor
+/// The monitor must not be used by any pool.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteMonitor<'a> {
+    /// The Cloudflare account of this monitor.
+    pub account_identifier: &'a str,
+    /// Which monitor to delete.
+    pub identifier: &'a str,
+}
+
+impl EndpointSpec for DeleteMonitor<'_> {
+    type JsonResponse = DeletedMonitor;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors/{}",
+            self.account_identifier, self.identifier
+        )
+    }
+}
+
+/// Preview Monitor
+/// Runs health checks with the given settings against the origins of the pools using a monitor,
+/// without changing the monitor.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/subresources/previews/methods/create/>
+#[derive(Debug)]
+pub struct PreviewMonitor<'a> {
+    /// The Cloudflare account of this monitor.
+    pub account_identifier: &'a str,
+    /// Which monitor's pools to check.
+    pub identifier: &'a str,
+    /// The settings to check with.
+    pub params: MonitorConfig,
+}
+
+impl EndpointSpec for PreviewMonitor<'_> {
+    type JsonResponse = MonitorPreview;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors/{}/preview",
+            self.account_identifier, self.identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Preview Result
+/// The result of a monitor preview. Results are available a few seconds after the preview is
+/// requested.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/previews/methods/get/>
+#[derive(Debug)]
+pub struct PreviewResultDetails<'a> {
+    pub account_identifier: &'a str,
+    /// The `preview_id` of a [`MonitorPreview`].
+    pub preview_identifier: &'a str,
+}
+
+impl EndpointSpec for PreviewResultDetails<'_> {
+    type JsonResponse = PreviewResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/preview/{}",
+            self.account_identifier, self.preview_identifier
+        )
+    }
+}
+
+/// List Monitor References
+/// Lists the resources using a monitor, e.g. before deleting it.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/monitors/subresources/references/methods/get/>
+#[derive(Debug)]
+pub struct ListMonitorReferences<'a> {
+    /// The Cloudflare account of this monitor.
+    pub account_identifier: &'a str,
+    pub identifier: &'a str,
+}
+
+impl EndpointSpec for ListMonitorReferences<'_> {
+    type JsonResponse = Vec<MonitorReference>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/monitors/{}/references",
+            self.account_identifier, self.identifier
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_monitor_kinds_round_trip() {
+        let mut config = MonitorConfig::new(MonitorKind::Https(HttpMonitor {
+            path: Some("/health".into()),
+            header: Some(HashMap::from([("Host".into(), vec!["example.com".into()])])),
+            expected_codes: Some("2xx".into()),
+            ..Default::default()
+        }));
+        config.interval = Some(30);
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({
+                "type": "https",
+                "path": "/health",
+                "header": {"Host": ["example.com"]},
+                "expected_codes": "2xx",
+                "interval": 30
+            })
+        );
+
+        let monitors: Vec<Monitor> = serde_json::from_value(json!([
+            {
+                "id": "m1",
+                "created_on": "2024-01-01T00:00:00Z",
+                "modified_on": "2024-01-01T00:00:00Z",
+                "type": "tcp",
+                "method": "connection_established",
+                "port": 8080,
+                "path": "",
+                "header": null,
+                "interval": 60,
+                "retries": 2,
+                "timeout": 5
+            },
+            {"id": "m2", "type": "icmp_ping", "description": "ping"}
+        ]))
+        .unwrap();
+        assert_eq!(
+            monitors[0].config.kind,
+            MonitorKind::Tcp(PortMonitor { port: Some(8080) })
+        );
+        assert_eq!(monitors[0].config.retries, Some(2));
+        assert_eq!(monitors[1].config.kind, MonitorKind::IcmpPing);
+        assert_eq!(monitors[1].config.description.as_deref(), Some("ping"));
+    }
+}
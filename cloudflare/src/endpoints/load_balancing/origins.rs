@@ -0,0 +1,182 @@
+use super::patch_pool::{self, PatchPool};
+use super::pool_details::PoolDetails;
+use super::{Origin, Pool};
+
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+
+#[derive(thiserror::Error, Debug)]
+pub enum OriginError {
+    #[error("API request failed: {0}")]
+    Api(#[from] ApiFailure),
+    #[error("pool {pool} has no origin named {origin:?}")]
+    NotFound { pool: String, origin: String },
+}
+
+/// Stops sending traffic to an origin of a pool, e.g. before maintenance. The other origins are
+/// left as is.
+pub async fn drain_origin(
+    client: &Client,
+    account_identifier: &str,
+    pool_identifier: &str,
+    origin_name: &str,
+) -> Result<Pool, OriginError> {
+    set_origin_enabled(
+        client,
+        account_identifier,
+        pool_identifier,
+        origin_name,
+        false,
+    )
+    .await
+}
+
+/// Sends traffic to an origin of a pool again, after [`drain_origin`].
+pub async fn enable_origin(
+    client: &Client,
+    account_identifier: &str,
+    pool_identifier: &str,
+    origin_name: &str,
+) -> Result<Pool, OriginError> {
+    set_origin_enabled(
+        client,
+        account_identifier,
+        pool_identifier,
+        origin_name,
+        true,
+    )
+    .await
+}
+
+/// Enables or disables the origin named `origin_name`, and returns the updated pool.
+///
+/// The API can only replace every origin of a pool at once, so this reads the pool first: a
+/// concurrent change to the pool's origins made between the two requests is lost.
+pub async fn set_origin_enabled(
+    client: &Client,
+    account_identifier: &str,
+    pool_identifier: &str,
+    origin_name: &str,
+    enabled: bool,
+) -> Result<Pool, OriginError> {
+    let pool = client
+        .request(&PoolDetails {
+            account_identifier,
+            identifier: pool_identifier,
+        })
+        .await?
+        .result;
+    if !pool.origins.iter().any(|o| o.name == origin_name) {
+        return Err(OriginError::NotFound {
+            pool: pool.id,
+            origin: origin_name.to_string(),
+        });
+    }
+    if pool
+        .origins
+        .iter()
+        .any(|o| o.name == origin_name && o.enabled == enabled)
+    {
+        return Ok(pool);
+    }
+
+    let origins: Vec<Origin> = pool
+        .origins
+        .into_iter()
+        .map(|mut origin| {
+            if origin.name == origin_name {
+                origin.enabled = enabled;
+            }
+            origin
+        })
+        .collect();
+    let pool = client
+        .request(&PatchPool {
+            account_identifier,
+            identifier: pool_identifier,
+            params: patch_pool::Params {
+                origins: Some(&origins),
+                ..Default::default()
+            },
+        })
+        .await?
+        .result;
+    Ok(pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::load_balancing::OriginAddress;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::{json, Value};
+
+    fn pool(web_enabled: bool) -> Value {
+        json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {
+                "id": "pool",
+                "created_on": "2024-01-01T00:00:00Z",
+                "modified_on": "2024-01-01T00:00:00Z",
+                "description": "",
+                "name": "primary",
+                "enabled": true,
+                "minimum_origins": 1,
+                "monitor": "monitor",
+                "check_regions": null,
+                "notification_email": "",
+                "origins": [
+                    {"name": "web", "address": "web.example.com", "enabled": web_enabled, "weight": 1.0, "port": 8080},
+                    {"name": "api", "address": "192.0.2.1", "enabled": true, "weight": 0.5,
+                     "header": {"Host": ["api.example.com"]}}
+                ]
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_drain_origin_keeps_the_other_origins() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/accounts/acc/load_balancers/pools/pool")
+            .with_body(pool(true).to_string())
+            .create();
+        let patch = server
+            .mock("PATCH", "/accounts/acc/load_balancers/pools/pool")
+            .match_body(Matcher::AllOf(vec![
+                Matcher::Regex(
+                    r#""name":"web","address":"web.example.com","enabled":false,"weight":1.0,"port":8080"#
+                        .into(),
+                ),
+                Matcher::Regex(r#""name":"api","address":"192.0.2.1","enabled":true"#.into()),
+            ]))
+            .with_body(pool(false).to_string())
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let pool = drain_origin(&client, "acc", "pool", "web").await.unwrap();
+
+        patch.assert();
+        let web = pool.origins.iter().find(|o| o.name == "web").unwrap();
+        assert!(!web.enabled);
+        assert_eq!(web.address, OriginAddress::from("web.example.com"));
+        let api = pool.origins.iter().find(|o| o.name == "api").unwrap();
+        assert_eq!(api.address, OriginAddress::from("192.0.2.1"));
+        assert!(matches!(api.address, OriginAddress::Ip(_)));
+
+        assert!(matches!(
+            drain_origin(&client, "acc", "pool", "missing").await,
+            Err(OriginError::NotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,143 @@
+use crate::endpoints::load_balancing::create_lb::OptionalParams;
+use crate::endpoints::load_balancing::{LbPoolId, LoadBalancer};
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+use serde::Serialize;
+
+/// Patch Load Balancer
+/// Changes only the given settings of a load balancer, e.g. its pools during a failover.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/methods/edit/>
+#[derive(Debug)]
+pub struct PatchLoadBalancer<'a> {
+    /// The Zone to which this Load Balancer belongs.
+    pub zone_identifier: &'a str,
+    /// Which load balancer to patch.
+    pub identifier: &'a str,
+    /// The settings to change. Settings left to `None` are kept.
+    pub params: Params<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct Params<'a> {
+    pub name: Option<&'a str>,
+    /// The list of LB Pools (by their IDs) ordered by their failover priority.
+    pub default_pools: Option<&'a [LbPoolId]>,
+    /// The LB Pool ID to use when all other pools are detected as unhealthy.
+    pub fallback_pool: Option<&'a LbPoolId>,
+    #[serde(flatten)]
+    pub optional_params: OptionalParams<'a>,
+}
+
+impl EndpointSpec for PatchLoadBalancer<'_> {
+    type JsonResponse = LoadBalancer;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/load_balancers/{}",
+            self.zone_identifier, self.identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::load_balancing::{RandomSteering, SteeringPolicy};
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_patch_only_sends_given_settings() {
+        let mut server = Server::new_async().await;
+        let lb = json!({
+            "id": "lb",
+            "created_on": "2024-01-01T00:00:00Z",
+            "modified_on": "2024-01-02T00:00:00Z",
+            "description": "",
+            "name": "www.example.com",
+            "enabled": true,
+            "fallback_pool": "backup",
+            "default_pools": ["backup", "primary"],
+            "region_pools": {},
+            "pop_pools": {},
+            "country_pools": {"US": ["primary"]},
+            "proxied": true,
+            "steering_policy": "least_outstanding_requests",
+            "random_steering": {"default_weight": 0.5, "pool_weights": {"primary": 0.8}},
+            "adaptive_routing": {"failover_across_pools": true},
+            "location_strategy": {"mode": "resolver_ip", "prefer_ecs": "always"},
+            "session_affinity": "none",
+            "session_affinity_attributes": {"samesite": "Auto", "secure": "Auto", "drain_duration": 0},
+            "networks": ["public"],
+            "zone_name": "example.com"
+        });
+        let patch = server
+            .mock("PATCH", "/zones/zone/load_balancers/lb")
+            .match_body(Matcher::Json(json!({
+                "default_pools": ["backup", "primary"],
+                "steering_policy": "least_outstanding_requests",
+                "random_steering": {"default_weight": 0.5, "pool_weights": {"primary": 0.8}}
+            })))
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": lb}).to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let default_pools = ["backup".to_string(), "primary".to_string()];
+        let random_steering = RandomSteering {
+            default_weight: Some(0.5),
+            pool_weights: HashMap::from([("primary".to_string(), 0.8)]),
+        };
+        let lb = client
+            .request(&PatchLoadBalancer {
+                zone_identifier: "zone",
+                identifier: "lb",
+                params: Params {
+                    default_pools: Some(&default_pools),
+                    optional_params: OptionalParams {
+                        steering_policy: Some(SteeringPolicy::LeastOutstandingRequests),
+                        random_steering: Some(random_steering.clone()),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+
+        patch.assert();
+        assert_eq!(lb.default_pools, default_pools);
+        assert_eq!(lb.random_steering, Some(random_steering));
+        let nan_weight = RandomSteering {
+            default_weight: Some(f64::NAN),
+            ..Default::default()
+        };
+        assert_eq!(nan_weight, nan_weight.clone());
+        assert_eq!(lb.country_pools["US"], ["primary"]);
+        assert_eq!(lb.zone_name.as_deref(), Some("example.com"));
+    }
+}
@@ -0,0 +1,83 @@
+use crate::endpoints::load_balancing::create_pool::OptionalParams;
+use crate::endpoints::load_balancing::{Origin, Pool};
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+use serde::Serialize;
+
+/// Patch Pool
+/// Changes only the given settings of a pool.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/pools/methods/edit/>
+#[derive(Debug)]
+pub struct PatchPool<'a> {
+    /// The Cloudflare account of this pool.
+    pub account_identifier: &'a str,
+    /// Which pool to patch.
+    pub identifier: &'a str,
+    /// The settings to change. Settings left to `None` are kept.
+    pub params: Params<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct Params<'a> {
+    pub name: Option<&'a str>,
+    /// The list of origins within this pool. Replaces every origin of the pool.
+    pub origins: Option<&'a [Origin]>,
+    #[serde(flatten)]
+    pub optional_params: OptionalParams<'a>,
+}
+
+impl EndpointSpec for PatchPool<'_> {
+    type JsonResponse = Pool;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/pools/{}",
+            self.account_identifier, self.identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Patch Pools
+/// Changes the given settings of every pool of an account. Only `notification_email` can be
+/// changed this way.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/pools/methods/bulk_edit/>
+#[derive(Debug)]
+pub struct PatchPools<'a> {
+    /// The Cloudflare account of the pools.
+    pub account_identifier: &'a str,
+    pub params: PatchPoolsParams<'a>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct PatchPoolsParams<'a> {
+    /// The email address to send health status notifications to. An empty string removes it
+    /// from every pool.
+    pub notification_email: &'a str,
+}
+
+impl EndpointSpec for PatchPools<'_> {
+    type JsonResponse = Vec<Pool>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/load_balancers/pools", self.account_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
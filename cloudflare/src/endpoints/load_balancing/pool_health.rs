@@ -0,0 +1,50 @@
+use crate::endpoints::load_balancing::OriginHealth;
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Pool Health Details
+/// The latest health check results of the origins of a pool, from each region checking them.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/pools/subresources/health/methods/get/>
+#[derive(Debug)]
+pub struct PoolHealthDetails<'a> {
+    /// The Cloudflare account of this pool.
+    pub account_identifier: &'a str,
+    /// Which pool to retrieve the health of.
+    pub identifier: &'a str,
+}
+
+impl EndpointSpec for PoolHealthDetails<'_> {
+    type JsonResponse = PoolHealth;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/pools/{}/health",
+            self.account_identifier, self.identifier
+        )
+    }
+}
+
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct PoolHealth {
+    pub pool_id: String,
+    /// The health of the pool as seen from each checking region, e.g. `Amsterdam, NL`.
+    #[serde(default)]
+    pub pop_health: HashMap<String, PopHealth>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct PopHealth {
+    pub healthy: Option<bool>,
+    /// The health of each origin of the pool, by origin address.
+    #[serde(default)]
+    pub origins: Vec<HashMap<String, OriginHealth>>,
+}
+
+impl ApiResult for PoolHealth {}
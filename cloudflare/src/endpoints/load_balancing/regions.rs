@@ -0,0 +1,119 @@
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::{Deserialize, Serialize};
+
+/// The regions usable as keys of `region_pools`, and the countries they contain.
+/// <https://developers.cloudflare.com/load-balancing/reference/region-mapping-api/>
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct Regions {
+    /// The standard the country and subdivision codes follow, e.g. `ISO 3166-1 alpha-2`.
+    pub iso_standard: Option<String>,
+    #[serde(default)]
+    pub regions: Vec<Region>,
+}
+
+impl Regions {
+    /// The code of the region a country belongs to, by ISO 3166-1 alpha-2 code, e.g. `US`.
+    pub fn region_of(&self, country_code: &str) -> Option<&str> {
+        self.regions
+            .iter()
+            .find(|region| {
+                region
+                    .countries
+                    .iter()
+                    .any(|c| c.country_code_a2.eq_ignore_ascii_case(country_code))
+            })
+            .map(|region| region.region_code.as_str())
+    }
+}
+
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct Region {
+    /// e.g. `WNAM` for Western North America
+    pub region_code: String,
+    #[serde(default)]
+    pub countries: Vec<RegionCountry>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct RegionCountry {
+    pub country_code_a2: String,
+    pub country_name: Option<String>,
+    /// For countries split between regions, the subdivisions of the country in the region.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub country_subdivisions: Vec<CountrySubdivision>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Eq, PartialEq, Deserialize, Serialize, Clone, Debug)]
+pub struct CountrySubdivision {
+    pub subdivision_code_a2: String,
+    pub subdivision_name: Option<String>,
+}
+
+impl ApiResult for Regions {}
+
+/// List Regions
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/regions/methods/list/>
+#[derive(Debug)]
+pub struct ListRegions<'a> {
+    pub account_identifier: &'a str,
+    pub params: ListRegionsParams<'a>,
+}
+
+/// Filters to find the region of a country or subdivision.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ListRegionsParams<'a> {
+    /// ISO 3166-1 alpha-2 code of a country, e.g. `US`.
+    pub country_code_a2: Option<&'a str>,
+    /// ISO 3166-2 code of a subdivision, e.g. `CA` for California.
+    pub subdivision_code: Option<&'a str>,
+    pub subdivision_code_a2: Option<&'a str>,
+}
+
+impl EndpointSpec for ListRegions<'_> {
+    type JsonResponse = Regions;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/regions",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+/// Region Details
+/// The countries of a single region.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/regions/methods/get/>
+#[derive(Debug)]
+pub struct RegionDetails<'a> {
+    pub account_identifier: &'a str,
+    /// The region code, e.g. `WNAM`.
+    pub region_code: &'a str,
+}
+
+impl EndpointSpec for RegionDetails<'_> {
+    type JsonResponse = Regions;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/regions/{}",
+            self.account_identifier, self.region_code
+        )
+    }
+}
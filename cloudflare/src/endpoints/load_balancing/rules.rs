@@ -0,0 +1,167 @@
+//! A builder for the conditions of [`LoadBalancerRule`](super::LoadBalancerRule)s, written in the
+//! Rules language.
+//! <https://developers.cloudflare.com/load-balancing/additional-options/load-balancing-rules/expressions/>
+
+use std::fmt;
+use std::ops::Not;
+
+/// A condition on the requests a load balancer rule applies to.
+///
+/// ```
+/// use cloudflare::endpoints::load_balancing::rules::RuleCondition;
+///
+/// let canary = RuleCondition::header_equals("X-Canary", "true")
+///     .and(RuleCondition::path_starts_with("/api/"));
+/// assert_eq!(
+///     canary.to_string(),
+///     r#"(any(http.request.headers["x-canary"][*] == "true")) and (starts_with(http.request.uri.path, "/api/"))"#
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuleCondition(String);
+
+impl RuleCondition {
+    /// A condition written by hand, for fields the builder doesn't cover.
+    pub fn raw(expression: impl Into<String>) -> Self {
+        RuleCondition(expression.into())
+    }
+
+    /// Requests with a header of this name, whatever its value. Header names are
+    /// case-insensitive.
+    pub fn header_exists(name: &str) -> Self {
+        RuleCondition(format!(
+            "len(http.request.headers[{}]) > 0",
+            quote(&name.to_lowercase())
+        ))
+    }
+
+    /// Requests with a header of this name set to exactly `value`.
+    pub fn header_equals(name: &str, value: &str) -> Self {
+        RuleCondition(format!(
+            "any(http.request.headers[{}][*] == {})",
+            quote(&name.to_lowercase()),
+            quote(value)
+        ))
+    }
+
+    /// Requests for exactly this path, without the query string.
+    pub fn path_equals(path: &str) -> Self {
+        RuleCondition(format!("http.request.uri.path == {}", quote(path)))
+    }
+
+    /// Requests for a path starting with `prefix`.
+    pub fn path_starts_with(prefix: &str) -> Self {
+        RuleCondition(format!(
+            "starts_with(http.request.uri.path, {})",
+            quote(prefix)
+        ))
+    }
+
+    /// Requests for this hostname.
+    pub fn host_equals(host: &str) -> Self {
+        RuleCondition(format!("http.host == {}", quote(host)))
+    }
+
+    /// Requests from one of these countries, by ISO 3166-1 alpha-2 code, e.g. `US`.
+    pub fn country_in<'a>(countries: impl IntoIterator<Item = &'a str>) -> Self {
+        RuleCondition(format!("ip.src.country in {}", set(countries)))
+    }
+
+    /// Requests from one of these continents, by code, e.g. `EU`.
+    pub fn continent_in<'a>(continents: impl IntoIterator<Item = &'a str>) -> Self {
+        RuleCondition(format!("ip.src.continent in {}", set(continents)))
+    }
+
+    /// Requests matching both conditions.
+    pub fn and(self, other: RuleCondition) -> Self {
+        RuleCondition(format!("({}) and ({})", self.0, other.0))
+    }
+
+    /// Requests matching either condition.
+    pub fn or(self, other: RuleCondition) -> Self {
+        RuleCondition(format!("({}) or ({})", self.0, other.0))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Requests not matching the condition.
+impl Not for RuleCondition {
+    type Output = RuleCondition;
+
+    fn not(self) -> Self {
+        RuleCondition(format!("not ({})", self.0))
+    }
+}
+
+impl fmt::Display for RuleCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<RuleCondition> for String {
+    fn from(condition: RuleCondition) -> Self {
+        condition.0
+    }
+}
+
+/// A string literal of the Rules language.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn set<'a>(values: impl IntoIterator<Item = &'a str>) -> String {
+    let values: Vec<String> = values.into_iter().map(quote).collect();
+    format!("{{{}}}", values.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::load_balancing::{FixedResponse, LoadBalancerRule};
+    use serde_json::json;
+
+    #[test]
+    fn test_canary_rule() {
+        let mut canary = LoadBalancerRule::new(
+            "canary",
+            RuleCondition::header_equals("X-Canary", "a \"quoted\" value"),
+        );
+        canary.overrides.default_pools = Some(vec!["canary-pool".to_string()]);
+        let mut blocked = LoadBalancerRule::new(
+            "blocked",
+            RuleCondition::country_in(["KP", "IR"]).and(!RuleCondition::path_equals("/")),
+        );
+        blocked.priority = 1;
+        blocked.fixed_response = Some(FixedResponse {
+            status_code: Some(451),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            serde_json::to_value([canary, blocked]).unwrap(),
+            json!([
+                {
+                    "name": "canary",
+                    "condition": r#"any(http.request.headers["x-canary"][*] == "a \"quoted\" value")"#,
+                    "disabled": false,
+                    "priority": 0,
+                    "terminates": false,
+                    "overrides": {"default_pools": ["canary-pool"]}
+                },
+                {
+                    "name": "blocked",
+                    "condition": r#"(ip.src.country in {"KP" "IR"}) and (not (http.request.uri.path == "/"))"#,
+                    "disabled": false,
+                    "priority": 1,
+                    "terminates": false,
+                    "overrides": {},
+                    "fixed_response": {"status_code": 451}
+                }
+            ])
+        );
+    }
+}
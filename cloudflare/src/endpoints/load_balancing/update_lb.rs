@@ -0,0 +1,39 @@
+use crate::endpoints::load_balancing::create_lb::Params;
+use crate::endpoints::load_balancing::LoadBalancer;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Update Load Balancer
+/// Replaces the whole configuration of a load balancer: settings left out are reset to their
+/// default. Use [`PatchLoadBalancer`](super::patch_lb::PatchLoadBalancer) to change only some of
+/// them.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/methods/update/>
+#[derive(Debug)]
+pub struct UpdateLoadBalancer<'a> {
+    /// The Zone to which this Load Balancer belongs.
+    pub zone_identifier: &'a str,
+    /// Which load balancer to update.
+    pub identifier: &'a str,
+    /// The new configuration, with the same parameters as when creating a load balancer.
+    pub params: Params<'a>,
+}
+
+impl EndpointSpec for UpdateLoadBalancer<'_> {
+    type JsonResponse = LoadBalancer;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "zones/{}/load_balancers/{}",
+            self.zone_identifier, self.identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
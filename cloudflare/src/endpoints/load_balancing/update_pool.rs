@@ -0,0 +1,38 @@
+use crate::endpoints::load_balancing::create_pool::Params;
+use crate::endpoints::load_balancing::Pool;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Update Pool
+/// Replaces the whole configuration of a pool: settings left out are reset to their default. Use
+/// [`PatchPool`](super::patch_pool::PatchPool) to change only some of them.
+/// <https://developers.cloudflare.com/api/resources/load_balancers/subresources/pools/methods/update/>
+#[derive(Debug)]
+pub struct UpdatePool<'a> {
+    /// The Cloudflare account of this pool.
+    pub account_identifier: &'a str,
+    /// Which pool to update.
+    pub identifier: &'a str,
+    /// The new configuration, with the same parameters as when creating a pool.
+    pub params: Params<'a>,
+}
+
+impl EndpointSpec for UpdatePool<'_> {
+    type JsonResponse = Pool;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/load_balancers/pools/{}",
+            self.account_identifier, self.identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
@@ -10,6 +10,8 @@ pub mod cfd_tunnel;
 pub mod dns;
 pub mod load_balancing;
 pub mod r2;
+pub mod teamnet;
 pub mod workers;
+pub mod workers_for_platforms;
 pub mod workerskv;
 pub mod zones;
@@ -0,0 +1,253 @@
+use super::r2::{jurisdiction_headers, EmptyResult, Jurisdiction};
+use super::{validate_ids, RuleError};
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The CORS rules of a bucket.
+/// <https://developers.cloudflare.com/r2/buckets/cors/>
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct CorsPolicy {
+    #[serde(default)]
+    pub rules: Vec<CorsRule>,
+}
+
+impl ApiResult for CorsPolicy {}
+
+impl CorsPolicy {
+    /// The maximum number of CORS rules of a bucket.
+    pub const MAX_RULES: usize = 100;
+
+    /// Checks the rules against the API's constraints before sending them.
+    pub fn validate(&self) -> Result<(), RuleError> {
+        validate_ids(self.rules.iter().map(|r| r.id.as_deref()), Self::MAX_RULES)?;
+        for (index, rule) in self.rules.iter().enumerate() {
+            let name = rule.id.clone().unwrap_or_else(|| format!("#{index}"));
+            let invalid = |reason| RuleError::Invalid {
+                rule: name.clone(),
+                reason,
+            };
+            if rule.allowed.origins.is_empty() {
+                return Err(invalid("at least one origin must be allowed"));
+            }
+            if rule.allowed.methods.is_empty() {
+                return Err(invalid("at least one method must be allowed"));
+            }
+            if rule
+                .allowed
+                .origins
+                .iter()
+                .any(|o| o.matches('*').count() > 1)
+            {
+                return Err(invalid("an origin can contain at most one wildcard"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A CORS rule: which cross-origin requests are allowed, and how browsers may cache the answer.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CorsRule {
+    /// Identifier for the rule, to tell rules apart.
+    pub id: Option<String>,
+    pub allowed: CorsAllowed,
+    /// Response headers browsers may expose to the requesting script.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expose_headers: Vec<String>,
+    /// How long browsers may cache the response to a preflight request.
+    pub max_age_seconds: Option<u32>,
+}
+
+/// The origins, methods and headers a [`CorsRule`] allows.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CorsAllowed {
+    /// Allowed origins, e.g. `https://example.com`. An origin can contain one `*` wildcard.
+    pub origins: Vec<String>,
+    pub methods: Vec<CorsMethod>,
+    /// Request headers allowed in cross-origin requests.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum CorsMethod {
+    Get,
+    Put,
+    Post,
+    Delete,
+    Head,
+}
+
+/// Gets the CORS policy of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/cors/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketCors<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetBucketCors<'_> {
+    type JsonResponse = CorsPolicy;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/cors",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Replaces the CORS policy of a bucket. Use [`CorsPolicy::validate`] to check it first.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/cors/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketCors<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: CorsPolicy,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for PutBucketCors<'_> {
+    type JsonResponse = EmptyResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/cors",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Removes the CORS policy of a bucket, disallowing every cross-origin request.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/cors/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteBucketCors<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for DeleteBucketCors<'_> {
+    type JsonResponse = EmptyResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/cors",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule(id: Option<&str>, origin: &str) -> CorsRule {
+        CorsRule {
+            id: id.map(str::to_string),
+            allowed: CorsAllowed {
+                origins: vec![origin.to_string()],
+                methods: vec![CorsMethod::Get, CorsMethod::Head],
+                headers: vec![],
+            },
+            expose_headers: vec!["ETag".to_string()],
+            max_age_seconds: Some(3600),
+        }
+    }
+
+    #[test]
+    fn test_cors_rule_serialization_and_validation() {
+        let policy = CorsPolicy {
+            rules: vec![rule(Some("site"), "https://*.example.com"), rule(None, "*")],
+        };
+        assert_eq!(policy.validate(), Ok(()));
+        assert_eq!(
+            serde_json::to_value(&policy).unwrap()["rules"][0],
+            json!({
+                "id": "site",
+                "allowed": {"origins": ["https://*.example.com"], "methods": ["GET", "HEAD"]},
+                "exposeHeaders": ["ETag"],
+                "maxAgeSeconds": 3600
+            })
+        );
+
+        let policy = CorsPolicy {
+            rules: vec![rule(None, "*"), rule(Some(""), "*")],
+        };
+        assert_eq!(policy.validate(), Err(RuleError::EmptyId(1)));
+        let policy = CorsPolicy {
+            rules: vec![rule(Some("a"), "*"), rule(None, "*"), rule(Some("a"), "*")],
+        };
+        assert_eq!(
+            policy.validate(),
+            Err(RuleError::DuplicateId("a".to_string()))
+        );
+        let policy = CorsPolicy {
+            rules: vec![rule(None, "*"); CorsPolicy::MAX_RULES + 1],
+        };
+        assert_eq!(
+            policy.validate(),
+            Err(RuleError::TooManyRules {
+                max: CorsPolicy::MAX_RULES,
+                actual: CorsPolicy::MAX_RULES + 1
+            })
+        );
+
+        let policy = CorsPolicy {
+            rules: vec![rule(None, "*"), rule(None, "https://*.*.example.com")],
+        };
+        assert!(matches!(
+            policy.validate(),
+            Err(RuleError::Invalid { rule, .. }) if rule == "#1"
+        ));
+        let mut no_methods = rule(Some("no-methods"), "*");
+        no_methods.allowed.methods.clear();
+        let policy = CorsPolicy {
+            rules: vec![no_methods],
+        };
+        assert!(matches!(
+            policy.validate(),
+            Err(RuleError::Invalid { rule, .. }) if rule == "no-methods"
+        ));
+    }
+}
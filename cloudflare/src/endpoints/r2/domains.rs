@@ -0,0 +1,389 @@
+use super::r2::{jurisdiction_headers, Jurisdiction};
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The minimum TLS version a custom domain accepts.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MinTlsVersion {
+    #[serde(rename = "1.0")]
+    Tls1_0,
+    #[serde(rename = "1.1")]
+    Tls1_1,
+    #[serde(rename = "1.2")]
+    Tls1_2,
+    #[serde(rename = "1.3")]
+    Tls1_3,
+}
+
+/// A custom domain serving the objects of a bucket.
+/// <https://developers.cloudflare.com/r2/buckets/public-buckets/#custom-domains>
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomDomain {
+    /// Domain name of the custom domain.
+    pub domain: String,
+    /// Whether the domain serves the bucket.
+    pub enabled: bool,
+    /// The provisioning status of the domain. Only returned when getting or listing domains.
+    pub status: Option<CustomDomainStatus>,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+    /// Zone ID of the custom domain.
+    pub zone_id: Option<String>,
+    /// Zone that the custom domain resides in.
+    pub zone_name: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomDomainStatus {
+    /// Ownership status of the domain, e.g. "active" or "pending".
+    pub ownership: String,
+    /// SSL certificate status, e.g. "active" or "pending".
+    pub ssl: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CustomDomains {
+    pub domains: Vec<CustomDomain>,
+}
+
+/// The result of removing a custom domain.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct RemovedCustomDomain {
+    pub domain: String,
+}
+
+/// The managed `r2.dev` public URL of a bucket.
+/// <https://developers.cloudflare.com/r2/buckets/public-buckets/#managed-public-access-through-r2dev>
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedDomain {
+    /// Bucket ID.
+    pub bucket_id: String,
+    /// Domain name of the bucket's r2.dev domain.
+    pub domain: String,
+    /// Whether the bucket is publicly accessible at its r2.dev domain.
+    pub enabled: bool,
+}
+
+impl ApiResult for CustomDomain {}
+impl ApiResult for CustomDomains {}
+impl ApiResult for RemovedCustomDomain {}
+impl ApiResult for ManagedDomain {}
+
+/// Lists the custom domains of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/list/>
+#[derive(Debug)]
+pub struct ListCustomDomains<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for ListCustomDomains<'_> {
+    type JsonResponse = CustomDomains;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Gets a custom domain of a bucket, with its provisioning status.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/get/>
+#[derive(Debug)]
+pub struct GetCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub domain: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetCustomDomain<'_> {
+    type JsonResponse = CustomDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom/{}",
+            self.account_identifier, self.bucket_name, self.domain
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Attaches a custom domain to a bucket. The domain must be in a zone of the same account.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/create/>
+#[derive(Debug)]
+pub struct AttachCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: AttachCustomDomainParams,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for AttachCustomDomain<'_> {
+    type JsonResponse = CustomDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct AttachCustomDomainParams {
+    /// Name of the custom domain to attach.
+    pub domain: String,
+    /// Whether the domain serves the bucket once attached.
+    pub enabled: bool,
+    /// Zone ID of the custom domain.
+    pub zone_id: String,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+}
+
+/// Updates the settings of a custom domain of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/update/>
+#[derive(Debug)]
+pub struct UpdateCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub domain: &'a str,
+    pub params: UpdateCustomDomainParams,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for UpdateCustomDomain<'_> {
+    type JsonResponse = CustomDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom/{}",
+            self.account_identifier, self.bucket_name, self.domain
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct UpdateCustomDomainParams {
+    /// Whether the domain serves the bucket.
+    pub enabled: Option<bool>,
+    #[serde(rename = "minTLS")]
+    pub min_tls: Option<MinTlsVersion>,
+}
+
+/// Removes a custom domain from a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/custom/methods/delete/>
+#[derive(Debug)]
+pub struct RemoveCustomDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub domain: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for RemoveCustomDomain<'_> {
+    type JsonResponse = RemovedCustomDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/custom/{}",
+            self.account_identifier, self.bucket_name, self.domain
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Gets the state of the managed `r2.dev` domain of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/managed/methods/list/>
+#[derive(Debug)]
+pub struct GetManagedDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetManagedDomain<'_> {
+    type JsonResponse = ManagedDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/managed",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Enables or disables public access to a bucket through its managed `r2.dev` domain.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/domains/subresources/managed/methods/update/>
+#[derive(Debug)]
+pub struct UpdateManagedDomain<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: UpdateManagedDomainParams,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for UpdateManagedDomain<'_> {
+    type JsonResponse = ManagedDomain;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/domains/managed",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct UpdateManagedDomainParams {
+    /// Whether to enable public access at the r2.dev domain.
+    pub enabled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_domain_field_names() {
+        let params = AttachCustomDomainParams {
+            domain: "cdn.example.com".to_string(),
+            enabled: true,
+            zone_id: "zone".to_string(),
+            min_tls: Some(MinTlsVersion::Tls1_2),
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({
+                "domain": "cdn.example.com",
+                "enabled": true,
+                "zoneId": "zone",
+                "minTLS": "1.2"
+            })
+        );
+
+        let domain: CustomDomain = serde_json::from_value(json!({
+            "domain": "cdn.example.com",
+            "enabled": true,
+            "status": {"ownership": "active", "ssl": "pending"},
+            "minTLS": "1.3",
+            "zoneId": "zone",
+            "zoneName": "example.com"
+        }))
+        .unwrap();
+        assert_eq!(domain.min_tls, Some(MinTlsVersion::Tls1_3));
+        assert_eq!(domain.zone_id.as_deref(), Some("zone"));
+        assert_eq!(domain.zone_name.as_deref(), Some("example.com"));
+        assert_eq!(domain.status.unwrap().ssl, "pending");
+        for (version, json) in [
+            (MinTlsVersion::Tls1_0, "1.0"),
+            (MinTlsVersion::Tls1_1, "1.1"),
+        ] {
+            assert_eq!(serde_json::to_value(version).unwrap(), json);
+        }
+
+        let managed: ManagedDomain = serde_json::from_value(json!({
+            "bucketId": "bucket-id",
+            "domain": "pub-123.r2.dev",
+            "enabled": false
+        }))
+        .unwrap();
+        assert_eq!(
+            managed,
+            ManagedDomain {
+                bucket_id: "bucket-id".to_string(),
+                domain: "pub-123.r2.dev".to_string(),
+                enabled: false,
+            }
+        );
+    }
+}
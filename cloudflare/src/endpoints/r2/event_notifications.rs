@@ -0,0 +1,265 @@
+use super::r2::{jurisdiction_headers, EmptyResult, Jurisdiction};
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The event notification rules of a bucket, grouped by destination queue.
+/// <https://developers.cloudflare.com/r2/buckets/event-notifications/>
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotificationConfiguration {
+    /// Name of the bucket.
+    pub bucket_name: Option<String>,
+    /// The queues that receive notifications, and their rules.
+    #[serde(default)]
+    pub queues: Vec<QueueNotificationRules>,
+}
+
+/// The rules sending notifications to one queue.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueNotificationRules {
+    /// Queue ID.
+    pub queue_id: String,
+    /// Name of the queue.
+    pub queue_name: Option<String>,
+    #[serde(default)]
+    pub rules: Vec<EventNotificationRule>,
+}
+
+impl ApiResult for EventNotificationConfiguration {}
+
+/// A rule sending a notification for the object events it matches.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct EventNotificationRule {
+    /// Rule ID. Set by the API.
+    #[serde(skip_serializing)]
+    pub rule_id: Option<String>,
+    /// Timestamp of when the rule was created. Set by the API.
+    #[serde(skip_serializing)]
+    pub created_at: Option<String>,
+    /// The object actions that trigger a notification.
+    pub actions: Vec<EventNotificationAction>,
+    /// Only objects whose key starts with this prefix trigger a notification.
+    pub prefix: Option<String>,
+    /// Only objects whose key ends with this suffix trigger a notification.
+    pub suffix: Option<String>,
+    /// A description that can be used to identify the rule.
+    pub description: Option<String>,
+}
+
+impl EventNotificationRule {
+    /// A rule matching every object affected by the given actions.
+    pub fn new(actions: Vec<EventNotificationAction>) -> Self {
+        EventNotificationRule {
+            rule_id: None,
+            created_at: None,
+            actions,
+            prefix: None,
+            suffix: None,
+            description: None,
+        }
+    }
+
+    /// Restricts the rule to the objects whose key starts with `prefix`.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Restricts the rule to the objects whose key ends with `suffix`.
+    pub fn with_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = Some(suffix.into());
+        self
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventNotificationAction {
+    /// An object was uploaded.
+    PutObject,
+    /// An object was copied.
+    CopyObject,
+    /// A multipart upload was completed.
+    CompleteMultipartUpload,
+    /// An object was deleted.
+    DeleteObject,
+    /// An object was deleted by a lifecycle rule.
+    LifecycleDeletion,
+}
+
+impl EventNotificationAction {
+    /// Every action creating an object.
+    pub const CREATE: [EventNotificationAction; 3] = [
+        EventNotificationAction::PutObject,
+        EventNotificationAction::CopyObject,
+        EventNotificationAction::CompleteMultipartUpload,
+    ];
+    /// Every action deleting an object.
+    pub const DELETE: [EventNotificationAction; 2] = [
+        EventNotificationAction::DeleteObject,
+        EventNotificationAction::LifecycleDeletion,
+    ];
+}
+
+/// Lists the event notification rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/event_notifications/subresources/r2/subresources/configuration/methods/get/>
+#[derive(Debug)]
+pub struct GetEventNotifications<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetEventNotifications<'_> {
+    type JsonResponse = EventNotificationConfiguration;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/event_notifications/r2/{}/configuration",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Adds event notification rules sending the bucket's object events to a queue.
+/// <https://developers.cloudflare.com/api/resources/event_notifications/subresources/r2/subresources/configuration/subresources/queues/methods/update/>
+#[derive(Debug)]
+pub struct PutEventNotificationRules<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub queue_identifier: &'a str,
+    pub params: PutEventNotificationRulesParams,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for PutEventNotificationRules<'_> {
+    type JsonResponse = EmptyResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/event_notifications/r2/{}/configuration/queues/{}",
+            self.account_identifier, self.bucket_name, self.queue_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct PutEventNotificationRulesParams {
+    pub rules: Vec<EventNotificationRule>,
+}
+
+/// Removes event notification rules sending the bucket's object events to a queue.
+/// <https://developers.cloudflare.com/api/resources/event_notifications/subresources/r2/subresources/configuration/subresources/queues/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteEventNotificationRules<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub queue_identifier: &'a str,
+    pub params: DeleteEventNotificationRulesParams,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for DeleteEventNotificationRules<'_> {
+    type JsonResponse = EmptyResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/event_notifications/r2/{}/configuration/queues/{}",
+            self.account_identifier, self.bucket_name, self.queue_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteEventNotificationRulesParams {
+    /// The rules to remove. Every rule of the queue is removed when empty.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rule_ids: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_rule_serializes_without_server_fields() {
+        let params = PutEventNotificationRulesParams {
+            rules: vec![
+                EventNotificationRule::new(EventNotificationAction::CREATE.to_vec())
+                    .with_prefix("images/")
+                    .with_suffix(".png"),
+            ],
+        };
+        assert_eq!(
+            serde_json::to_value(&params).unwrap(),
+            json!({"rules": [{
+                "actions": ["PutObject", "CopyObject", "CompleteMultipartUpload"],
+                "prefix": "images/",
+                "suffix": ".png"
+            }]})
+        );
+
+        let config: EventNotificationConfiguration = serde_json::from_value(json!({
+            "bucketName": "bucket",
+            "queues": [{
+                "queueId": "q",
+                "queueName": "events",
+                "rules": [{
+                    "ruleId": "r",
+                    "createdAt": "2024-09-19T21:54:48.405Z",
+                    "actions": ["DeleteObject"],
+                    "prefix": "",
+                    "suffix": ""
+                }]
+            }]
+        }))
+        .unwrap();
+        assert_eq!(config.queues[0].rules[0].rule_id.as_deref(), Some("r"));
+    }
+}
@@ -0,0 +1,259 @@
+use super::r2::{jurisdiction_headers, EmptyResult, Jurisdiction, StorageClass};
+use super::{validate_ids, RuleError};
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The object lifecycle rules of a bucket.
+/// <https://developers.cloudflare.com/r2/buckets/object-lifecycles/>
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecyclePolicy {
+    #[serde(default)]
+    pub rules: Vec<LifecycleRule>,
+}
+
+impl ApiResult for LifecyclePolicy {}
+
+impl LifecyclePolicy {
+    /// The maximum number of lifecycle rules of a bucket.
+    pub const MAX_RULES: usize = 1000;
+
+    /// Checks the rules against the API's constraints before sending them.
+    pub fn validate(&self) -> Result<(), RuleError> {
+        validate_ids(
+            self.rules.iter().map(|r| Some(r.id.as_str())),
+            Self::MAX_RULES,
+        )?;
+        for rule in &self.rules {
+            let invalid = |reason| RuleError::Invalid {
+                rule: rule.id.clone(),
+                reason,
+            };
+            if rule.delete_objects_transition.is_none()
+                && rule.abort_multipart_uploads_transition.is_none()
+                && rule.storage_class_transitions.is_empty()
+            {
+                return Err(invalid("a rule needs at least one transition"));
+            }
+            if let Some(abort) = &rule.abort_multipart_uploads_transition {
+                if !matches!(abort.condition, LifecycleCondition::Age { .. }) {
+                    return Err(invalid(
+                        "aborting multipart uploads only supports an age condition",
+                    ));
+                }
+            }
+            if rule
+                .storage_class_transitions
+                .iter()
+                .any(|t| t.storage_class != StorageClass::InfrequentAccess)
+            {
+                return Err(invalid(
+                    "objects can only transition to the InfrequentAccess storage class",
+                ));
+            }
+            let conditions = rule
+                .delete_objects_transition
+                .iter()
+                .map(|t| &t.condition)
+                .chain(
+                    rule.abort_multipart_uploads_transition
+                        .iter()
+                        .map(|t| &t.condition),
+                )
+                .chain(rule.storage_class_transitions.iter().map(|t| &t.condition));
+            for condition in conditions {
+                if let LifecycleCondition::Age { max_age: 0 } = condition {
+                    return Err(invalid("an age condition must be at least one second"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A lifecycle rule: what to do with the objects matching its conditions, and when.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct LifecycleRule {
+    /// Unique identifier for the rule.
+    pub id: String,
+    /// Whether or not the rule is in effect.
+    pub enabled: bool,
+    /// The objects the rule applies to.
+    pub conditions: LifecycleRuleConditions,
+    /// Deletes the objects once the condition is met.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delete_objects_transition: Option<LifecycleTransition>,
+    /// Aborts the incomplete multipart uploads once the condition is met.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub abort_multipart_uploads_transition: Option<LifecycleTransition>,
+    /// Moves the objects to another storage class once the condition is met.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub storage_class_transitions: Vec<StorageClassTransition>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct LifecycleRuleConditions {
+    /// Only objects whose key starts with this prefix match. Empty for every object.
+    #[serde(default)]
+    pub prefix: String,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct LifecycleTransition {
+    pub condition: LifecycleCondition,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageClassTransition {
+    pub condition: LifecycleCondition,
+    pub storage_class: StorageClass,
+}
+
+/// When a lifecycle transition happens.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum LifecycleCondition {
+    /// Once the object is this many seconds old.
+    Age {
+        #[serde(rename = "maxAge")]
+        max_age: u64,
+    },
+    /// From this date on.
+    Date { date: DateTime<Utc> },
+}
+
+/// Gets the object lifecycle rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/lifecycle/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketLifecycle<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetBucketLifecycle<'_> {
+    type JsonResponse = LifecyclePolicy;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lifecycle",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Replaces the object lifecycle rules of a bucket. Use [`LifecyclePolicy::validate`] to check
+/// them first.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/lifecycle/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketLifecycle<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: LifecyclePolicy,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for PutBucketLifecycle<'_> {
+    type JsonResponse = EmptyResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lifecycle",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule(id: &str) -> LifecycleRule {
+        LifecycleRule {
+            id: id.to_string(),
+            enabled: true,
+            conditions: LifecycleRuleConditions {
+                prefix: "logs/".to_string(),
+            },
+            delete_objects_transition: Some(LifecycleTransition {
+                condition: LifecycleCondition::Age { max_age: 86400 },
+            }),
+            abort_multipart_uploads_transition: None,
+            storage_class_transitions: vec![StorageClassTransition {
+                condition: LifecycleCondition::Age { max_age: 3600 },
+                storage_class: StorageClass::InfrequentAccess,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_lifecycle_rule_serialization_and_validation() {
+        let policy = LifecyclePolicy {
+            rules: vec![rule("expire-logs")],
+        };
+        assert_eq!(policy.validate(), Ok(()));
+        assert_eq!(
+            serde_json::to_value(&policy).unwrap(),
+            json!({"rules": [{
+                "id": "expire-logs",
+                "enabled": true,
+                "conditions": {"prefix": "logs/"},
+                "deleteObjectsTransition": {"condition": {"type": "Age", "maxAge": 86400}},
+                "storageClassTransitions": [{
+                    "condition": {"type": "Age", "maxAge": 3600},
+                    "storageClass": "InfrequentAccess"
+                }]
+            }]})
+        );
+
+        let mut abort_by_date = rule("abort");
+        abort_by_date.abort_multipart_uploads_transition = Some(LifecycleTransition {
+            condition: LifecycleCondition::Date {
+                date: "2030-01-01T00:00:00Z".parse().unwrap(),
+            },
+        });
+        let policy = LifecyclePolicy {
+            rules: vec![rule("a"), rule("a"), abort_by_date.clone()],
+        };
+        assert_eq!(
+            policy.validate(),
+            Err(RuleError::DuplicateId("a".to_string()))
+        );
+        let policy = LifecyclePolicy {
+            rules: vec![abort_by_date],
+        };
+        assert!(matches!(
+            policy.validate(),
+            Err(RuleError::Invalid { rule, .. }) if rule == "abort"
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use super::r2::{jurisdiction_headers, EmptyResult, Jurisdiction};
+use super::{validate_ids, RuleError};
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::{DateTime, Utc};
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The bucket lock rules of a bucket, which prevent objects from being deleted or overwritten.
+/// <https://developers.cloudflare.com/r2/buckets/bucket-locks/>
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct BucketLockPolicy {
+    #[serde(default)]
+    pub rules: Vec<BucketLockRule>,
+}
+
+impl ApiResult for BucketLockPolicy {}
+
+impl BucketLockPolicy {
+    /// The maximum number of lock rules of a bucket.
+    pub const MAX_RULES: usize = 1000;
+
+    /// Checks the rules against the API's constraints before sending them.
+    pub fn validate(&self) -> Result<(), RuleError> {
+        validate_ids(
+            self.rules.iter().map(|r| Some(r.id.as_str())),
+            Self::MAX_RULES,
+        )?;
+        for rule in &self.rules {
+            if let BucketLockCondition::Age { max_age_seconds: 0 } = rule.condition {
+                return Err(RuleError::Invalid {
+                    rule: rule.id.clone(),
+                    reason: "an age condition must be at least one second",
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A lock rule: the objects matching it can't be deleted or overwritten while its condition holds.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct BucketLockRule {
+    /// Unique identifier for the rule.
+    pub id: String,
+    /// Whether or not the rule is in effect.
+    pub enabled: bool,
+    /// Only objects whose key starts with this prefix are locked. `None` for every object.
+    pub prefix: Option<String>,
+    pub condition: BucketLockCondition,
+}
+
+/// How long objects stay locked.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(tag = "type")]
+pub enum BucketLockCondition {
+    /// Until the object is this many seconds old.
+    Age {
+        #[serde(rename = "maxAgeSeconds")]
+        max_age_seconds: u64,
+    },
+    /// Until this date.
+    Date { date: DateTime<Utc> },
+    /// Until the rule is removed.
+    Indefinite,
+}
+
+/// Gets the lock rules of a bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/locks/methods/get/>
+#[derive(Debug)]
+pub struct GetBucketLock<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetBucketLock<'_> {
+    type JsonResponse = BucketLockPolicy;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lock",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Replaces the lock rules of a bucket. Use [`BucketLockPolicy::validate`] to check them first.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/subresources/locks/methods/update/>
+#[derive(Debug)]
+pub struct PutBucketLock<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    pub params: BucketLockPolicy,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for PutBucketLock<'_> {
+    type JsonResponse = EmptyResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}/lock",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rule(id: &str, condition: BucketLockCondition) -> BucketLockRule {
+        BucketLockRule {
+            id: id.to_string(),
+            enabled: true,
+            prefix: Some("invoices/".to_string()),
+            condition,
+        }
+    }
+
+    #[test]
+    fn test_bucket_lock_rule_serialization_and_validation() {
+        let policy = BucketLockPolicy {
+            rules: vec![
+                rule(
+                    "retain",
+                    BucketLockCondition::Age {
+                        max_age_seconds: 86400,
+                    },
+                ),
+                rule(
+                    "until",
+                    BucketLockCondition::Date {
+                        date: "2030-01-01T00:00:00Z".parse().unwrap(),
+                    },
+                ),
+                rule("forever", BucketLockCondition::Indefinite),
+            ],
+        };
+        assert_eq!(policy.validate(), Ok(()));
+        assert_eq!(
+            serde_json::to_value(&policy).unwrap(),
+            json!({"rules": [
+                {
+                    "id": "retain",
+                    "enabled": true,
+                    "prefix": "invoices/",
+                    "condition": {"type": "Age", "maxAgeSeconds": 86400}
+                },
+                {
+                    "id": "until",
+                    "enabled": true,
+                    "prefix": "invoices/",
+                    "condition": {"type": "Date", "date": "2030-01-01T00:00:00Z"}
+                },
+                {
+                    "id": "forever",
+                    "enabled": true,
+                    "prefix": "invoices/",
+                    "condition": {"type": "Indefinite"}
+                }
+            ]})
+        );
+
+        let policy = BucketLockPolicy {
+            rules: vec![
+                rule("a", BucketLockCondition::Indefinite),
+                rule("a", BucketLockCondition::Indefinite),
+            ],
+        };
+        assert_eq!(
+            policy.validate(),
+            Err(RuleError::DuplicateId("a".to_string()))
+        );
+        let policy = BucketLockPolicy {
+            rules: vec![rule("", BucketLockCondition::Indefinite)],
+        };
+        assert_eq!(policy.validate(), Err(RuleError::EmptyId(0)));
+        let policy = BucketLockPolicy {
+            rules: vec![rule(
+                "zero",
+                BucketLockCondition::Age { max_age_seconds: 0 },
+            )],
+        };
+        assert!(matches!(
+            policy.validate(),
+            Err(RuleError::Invalid { rule, .. }) if rule == "zero"
+        ));
+    }
+}
@@ -1 +1,85 @@
+pub mod cors;
+pub mod domains;
+pub mod event_notifications;
+pub mod lifecycle;
+pub mod lock;
+#[cfg(feature = "r2-objects")]
+pub mod objects;
 pub mod r2;
+pub mod sippy;
+pub mod slurper;
+pub mod temp_access_credentials;
+
+use serde::{Deserialize, Serialize};
+
+/// An access key of AWS S3, or of another S3-compatible store such as R2.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+/// The credentials of a Google Cloud service account.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct GcsCredentials {
+    pub client_email: String,
+    /// The PEM-encoded private key of the service account.
+    pub private_key: String,
+}
+
+impl GcsCredentials {
+    /// Reads the credentials from the JSON key file of a service account.
+    pub fn from_service_account_key(json: &str) -> serde_json::Result<Self> {
+        #[derive(Deserialize)]
+        struct KeyFile {
+            client_email: String,
+            private_key: String,
+        }
+
+        let key: KeyFile = serde_json::from_str(json)?;
+        Ok(GcsCredentials {
+            client_email: key.client_email,
+            private_key: key.private_key,
+        })
+    }
+}
+
+/// A reason an R2 bucket rule set was rejected by its `validate` method.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum RuleError {
+    #[error("a bucket can have at most {max} rules, got {actual}")]
+    TooManyRules { max: usize, actual: usize },
+    #[error("rule #{0} has an empty id")]
+    EmptyId(usize),
+    #[error("more than one rule has the id {0:?}")]
+    DuplicateId(String),
+    #[error("rule {rule:?} is invalid: {reason}")]
+    Invalid { rule: String, reason: &'static str },
+}
+
+/// Checks the rule count and that rule ids are non-empty and unique.
+/// Rules without an id, `None`, only count towards the maximum.
+fn validate_ids<'a>(
+    ids: impl ExactSizeIterator<Item = Option<&'a str>>,
+    max: usize,
+) -> Result<(), RuleError> {
+    if ids.len() > max {
+        return Err(RuleError::TooManyRules {
+            max,
+            actual: ids.len(),
+        });
+    }
+    let mut seen = std::collections::HashSet::new();
+    for (index, id) in ids.enumerate() {
+        let Some(id) = id else { continue };
+        if id.is_empty() {
+            return Err(RuleError::EmptyId(index));
+        }
+        if !seen.insert(id) {
+            return Err(RuleError::DuplicateId(id.to_string()));
+        }
+    }
+    Ok(())
+}
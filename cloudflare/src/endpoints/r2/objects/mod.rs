@@ -0,0 +1,1055 @@
+/*!
+A client for the S3-compatible API of R2, to read and write the objects in a bucket.
+
+The control plane endpoints in [`endpoints::r2`](super) manage buckets; this client talks to
+`https://<account>.r2.cloudflarestorage.com` instead, signing each request with AWS Signature
+Version 4. It needs an R2 access key rather than a Cloudflare API token.
+
+Requires the `r2-objects` feature.
+ */
+mod sigv4;
+
+use super::r2::{Jurisdiction, StorageClass};
+
+use crate::framework::endpoint::StreamBody;
+use crate::framework::response::ByteStream;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use serde::{Deserialize, Serialize};
+use sigv4::{CanonicalRequest, UNSIGNED_PAYLOAD};
+use std::collections::BTreeMap;
+use std::io;
+use std::time::Duration;
+use url::Url;
+
+/// An R2 access key. Session tokens come with temporary credentials.
+#[derive(Clone, Debug)]
+pub struct R2Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum ObjectsError {
+    #[error("Reqwest returned an error when connecting to R2: {0}")]
+    Http(#[from] reqwest::Error),
+    /// R2 answered with an error, e.g. `NoSuchKey`.
+    #[error("R2 returned HTTP {status} {code}: {message}")]
+    Api {
+        status: StatusCode,
+        code: String,
+        message: String,
+    },
+    #[error("R2 returned an invalid XML response: {0}")]
+    Xml(#[from] quick_xml::DeError),
+    /// An option or credential can't be sent as an HTTP header.
+    #[error("invalid header {0:?}")]
+    InvalidHeader(String),
+    #[error("presigned URLs must expire after one second to seven days, got {0:?}")]
+    InvalidExpiry(Duration),
+    #[error("{0:?} is not a valid account identifier")]
+    InvalidAccountIdentifier(String),
+    /// R2 accepted a part without returning its ETag, which is needed to complete the upload.
+    #[error("R2 returned no ETag for part {0}")]
+    MissingETag(u16),
+    #[error("a streaming request body can only be sent once")]
+    BodyAlreadySent,
+    /// URLs resolve `.` and `..` segments, even percent-encoded ones, so a key holding such a
+    /// segment, e.g. `a/../b`, would address another object.
+    #[error("{0:?} has a `.` or `..` segment, which can't be addressed in a URL")]
+    UnaddressableKey(String),
+}
+
+impl ObjectsError {
+    /// Whether R2 answered with a 404, e.g. for a missing object, bucket or upload.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, ObjectsError::Api { status, .. } if *status == StatusCode::NOT_FOUND)
+    }
+}
+
+/// A range of bytes of an object.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByteRange {
+    /// From the first offset to the second one, both included.
+    Inclusive(u64, u64),
+    /// From an offset to the end of the object.
+    From(u64),
+    /// The given number of bytes at the end of the object.
+    Suffix(u64),
+}
+
+impl ByteRange {
+    fn header_value(&self) -> String {
+        match self {
+            ByteRange::Inclusive(start, end) => format!("bytes={start}-{end}"),
+            ByteRange::From(start) => format!("bytes={start}-"),
+            ByteRange::Suffix(length) => format!("bytes=-{length}"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PutObjectOptions {
+    pub content_type: Option<String>,
+    pub cache_control: Option<String>,
+    pub content_disposition: Option<String>,
+    /// Storage class of the object. Defaults to the storage class of the bucket.
+    pub storage_class: Option<StorageClass>,
+    /// Custom metadata, sent as `x-amz-meta-*` headers.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl PutObjectOptions {
+    fn headers(&self) -> Result<HeaderMap, ObjectsError> {
+        let mut headers = HeaderMap::new();
+        let fields = [
+            (reqwest::header::CONTENT_TYPE, &self.content_type),
+            (reqwest::header::CACHE_CONTROL, &self.cache_control),
+            (
+                reqwest::header::CONTENT_DISPOSITION,
+                &self.content_disposition,
+            ),
+        ];
+        for (name, value) in fields {
+            if let Some(value) = value {
+                let value = header_value(name.as_str(), value)?;
+                headers.insert(name, value);
+            }
+        }
+        if let Some(storage_class) = self.storage_class {
+            let value = match storage_class {
+                StorageClass::Standard => "STANDARD",
+                StorageClass::InfrequentAccess => "STANDARD_IA",
+            };
+            headers.insert("x-amz-storage-class", HeaderValue::from_static(value));
+        }
+        for (name, value) in &self.metadata {
+            let name = format!("x-amz-meta-{}", name.to_lowercase());
+            let value = header_value(&name, value)?;
+            let name = HeaderName::try_from(name.as_str())
+                .map_err(|_| ObjectsError::InvalidHeader(name.clone()))?;
+            headers.insert(name, value);
+        }
+        Ok(headers)
+    }
+}
+
+/// The longest a presigned URL can be valid for.
+pub const MAX_PRESIGNED_EXPIRY: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// The operations a presigned URL can be generated for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PresignMethod {
+    /// Downloads the object, optionally with a `Range` header.
+    Get,
+    /// Uploads the object. Headers such as `Content-Type` can be sent as they aren't signed.
+    Put,
+}
+
+/// The properties of an object, as returned by `HeadObject` and `GetObject`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ObjectHead {
+    /// The size of the object, or of the returned range.
+    pub content_length: Option<u64>,
+    pub content_type: Option<String>,
+    pub etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub cache_control: Option<String>,
+    /// Custom metadata, from the `x-amz-meta-*` headers.
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl ObjectHead {
+    fn from_headers(headers: &HeaderMap) -> Self {
+        let text = |name: HeaderName| {
+            headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        ObjectHead {
+            content_length: text(reqwest::header::CONTENT_LENGTH).and_then(|v| v.parse().ok()),
+            content_type: text(reqwest::header::CONTENT_TYPE),
+            etag: text(reqwest::header::ETAG),
+            last_modified: text(reqwest::header::LAST_MODIFIED)
+                .and_then(|v| DateTime::parse_from_rfc2822(&v).ok())
+                .map(|date| date.with_timezone(&Utc)),
+            cache_control: text(reqwest::header::CACHE_CONTROL),
+            metadata: headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    let name = name.as_str().strip_prefix("x-amz-meta-")?;
+                    Some((name.to_string(), value.to_str().ok()?.to_string()))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// An object read with [`ObjectsClient::get_object`].
+#[derive(Clone, Debug)]
+pub struct GetObjectOutput {
+    pub head: ObjectHead,
+    /// The returned range, e.g. `bytes 0-9/100`, for ranged reads.
+    pub content_range: Option<String>,
+    pub body: Bytes,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ListObjectsV2Params {
+    /// Only list the keys starting with this prefix.
+    pub prefix: Option<String>,
+    /// Groups the keys containing this delimiter after the prefix into `common_prefixes`.
+    pub delimiter: Option<String>,
+    /// The `next_continuation_token` of the previous page.
+    pub continuation_token: Option<String>,
+    /// Only list the keys after this one.
+    pub start_after: Option<String>,
+    /// Maximum number of keys to return, up to 1000.
+    pub max_keys: Option<u32>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ListObjectsV2Output {
+    pub name: String,
+    #[serde(default)]
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub key_count: u32,
+    #[serde(default)]
+    pub is_truncated: bool,
+    /// Pass this as `continuation_token` to get the next page.
+    #[serde(default)]
+    pub next_continuation_token: Option<String>,
+    #[serde(default)]
+    pub contents: Vec<ObjectSummary>,
+    #[serde(default)]
+    pub common_prefixes: Vec<CommonPrefix>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct ObjectSummary {
+    pub key: String,
+    pub size: u64,
+    #[serde(rename = "ETag")]
+    pub etag: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub storage_class: Option<String>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CommonPrefix {
+    pub prefix: String,
+}
+
+#[derive(Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteObjectsOutput {
+    #[serde(default)]
+    pub deleted: Vec<DeletedObject>,
+    #[serde(default, rename = "Error")]
+    pub errors: Vec<DeleteObjectError>,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeletedObject {
+    pub key: String,
+}
+
+#[derive(Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct DeleteObjectError {
+    pub key: String,
+    pub code: String,
+    #[serde(default)]
+    pub message: String,
+}
+
+/// A part uploaded with [`ObjectsClient::upload_part`], to pass to
+/// [`ObjectsClient::complete_multipart_upload`].
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "PascalCase")]
+pub struct CompletedPart {
+    pub part_number: u16,
+    #[serde(rename = "ETag")]
+    pub etag: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct InitiateMultipartUploadResult {
+    upload_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct CompleteMultipartUploadResult {
+    #[serde(rename = "ETag")]
+    etag: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ErrorResponse {
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// A client for the objects of R2 buckets, through the S3-compatible API.
+#[derive(Clone, Debug)]
+pub struct ObjectsClient {
+    http_client: reqwest::Client,
+    endpoint: Url,
+    credentials: R2Credentials,
+}
+
+impl ObjectsClient {
+    /// A client for the buckets of an account, in the given jurisdiction if any.
+    pub fn new(
+        account_identifier: &str,
+        jurisdiction: Option<Jurisdiction>,
+        credentials: R2Credentials,
+    ) -> Result<Self, ObjectsError> {
+        let host = match jurisdiction {
+            None | Some(Jurisdiction::Default) => {
+                format!("{account_identifier}.r2.cloudflarestorage.com")
+            }
+            Some(Jurisdiction::Eu) => format!("{account_identifier}.eu.r2.cloudflarestorage.com"),
+            Some(Jurisdiction::Fedramp) => {
+                format!("{account_identifier}.fedramp.r2.cloudflarestorage.com")
+            }
+        };
+        let endpoint = Url::parse(&format!("https://{host}"))
+            .ok()
+            .filter(|url| url.path() == "/")
+            .ok_or_else(|| {
+                ObjectsError::InvalidAccountIdentifier(account_identifier.to_string())
+            })?;
+        Self::with_endpoint(endpoint, credentials)
+    }
+
+    /// A client for another S3-compatible endpoint, e.g. a local stand-in in tests.
+    pub fn with_endpoint(endpoint: Url, credentials: R2Credentials) -> Result<Self, ObjectsError> {
+        Ok(ObjectsClient {
+            http_client: reqwest::Client::builder().build()?,
+            endpoint,
+            credentials,
+        })
+    }
+
+    /// Uploads an object, replacing any object with the same key. Returns its ETag.
+    ///
+    /// The body is streamed, e.g. from a file with [`StreamBody::from_async_read`]. Give it its
+    /// length with [`StreamBody::with_length`]: without it, it is sent with chunked transfer
+    /// encoding.
+    pub async fn put_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        body: impl Into<StreamBody>,
+        options: &PutObjectOptions,
+    ) -> Result<Option<String>, ObjectsError> {
+        let body = body.into();
+        let mut headers = options.headers()?;
+        if let Some(length) = body.length() {
+            headers.insert(reqwest::header::CONTENT_LENGTH, HeaderValue::from(length));
+        }
+        let source = body.take().map_err(|_| ObjectsError::BodyAlreadySent)?;
+        let response = self
+            .send(
+                Method::PUT,
+                bucket,
+                Some(key),
+                &[],
+                headers,
+                Some(reqwest::Body::wrap_stream(source.into_stream())),
+            )
+            .await?;
+        Ok(ObjectHead::from_headers(response.headers()).etag)
+    }
+
+    /// Reads an object, or a range of it.
+    pub async fn get_object(
+        &self,
+        bucket: &str,
+        key: &str,
+        range: Option<ByteRange>,
+    ) -> Result<GetObjectOutput, ObjectsError> {
+        let response = self.get(bucket, key, range).await?;
+        let head = ObjectHead::from_headers(response.headers());
+        let content_range = response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        Ok(GetObjectOutput {
+            head,
+            content_range,
+            body: response.bytes().await?,
+        })
+    }
+
+    /// Reads an object, or a range of it, without buffering its body.
+    pub async fn get_object_stream(
+        &self,
+        bucket: &str,
+        key: &str,
+        range: Option<ByteRange>,
+    ) -> Result<(ObjectHead, ByteStream), ObjectsError> {
+        let response = self.get(bucket, key, range).await?;
+        let head = ObjectHead::from_headers(response.headers());
+        let stream = response.bytes_stream().map_err(io::Error::other);
+        Ok((head, ByteStream::new(stream)))
+    }
+
+    /// Gets the properties of an object. Fails with a 404 if it doesn't exist.
+    pub async fn head_object(&self, bucket: &str, key: &str) -> Result<ObjectHead, ObjectsError> {
+        let response = self
+            .send(Method::HEAD, bucket, Some(key), &[], HeaderMap::new(), None)
+            .await?;
+        Ok(ObjectHead::from_headers(response.headers()))
+    }
+
+    /// Deletes an object. Succeeds if it doesn't exist.
+    pub async fn delete_object(&self, bucket: &str, key: &str) -> Result<(), ObjectsError> {
+        self.send(
+            Method::DELETE,
+            bucket,
+            Some(key),
+            &[],
+            HeaderMap::new(),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Deletes up to 1000 objects in a single request.
+    pub async fn delete_objects(
+        &self,
+        bucket: &str,
+        keys: &[&str],
+    ) -> Result<DeleteObjectsOutput, ObjectsError> {
+        #[derive(Serialize)]
+        #[serde(rename = "Delete")]
+        struct Delete<'a> {
+            #[serde(rename = "Object")]
+            objects: Vec<ObjectIdentifier<'a>>,
+        }
+        #[derive(Serialize)]
+        struct ObjectIdentifier<'a> {
+            #[serde(rename = "Key")]
+            key: &'a str,
+        }
+
+        let body = quick_xml::se::to_string(&Delete {
+            objects: keys.iter().map(|key| ObjectIdentifier { key }).collect(),
+        })
+        .expect("Failed to serialize keys");
+        let response = self
+            .send(
+                Method::POST,
+                bucket,
+                None,
+                &[("delete", String::new())],
+                xml_headers(),
+                Some(body.into()),
+            )
+            .await?;
+        parse_xml(response).await
+    }
+
+    /// Lists a page of the objects of a bucket.
+    pub async fn list_objects_v2(
+        &self,
+        bucket: &str,
+        params: &ListObjectsV2Params,
+    ) -> Result<ListObjectsV2Output, ObjectsError> {
+        let mut query = vec![("list-type", "2".to_string())];
+        let optional = [
+            ("prefix", &params.prefix),
+            ("delimiter", &params.delimiter),
+            ("continuation-token", &params.continuation_token),
+            ("start-after", &params.start_after),
+        ];
+        for (name, value) in optional {
+            if let Some(value) = value {
+                query.push((name, value.clone()));
+            }
+        }
+        if let Some(max_keys) = params.max_keys {
+            query.push(("max-keys", max_keys.to_string()));
+        }
+        let response = self
+            .send(Method::GET, bucket, None, &query, HeaderMap::new(), None)
+            .await?;
+        parse_xml(response).await
+    }
+
+    /// Starts a multipart upload, and returns its upload id.
+    pub async fn create_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        options: &PutObjectOptions,
+    ) -> Result<String, ObjectsError> {
+        let response = self
+            .send(
+                Method::POST,
+                bucket,
+                Some(key),
+                &[("uploads", String::new())],
+                options.headers()?,
+                None,
+            )
+            .await?;
+        let result: InitiateMultipartUploadResult = parse_xml(response).await?;
+        Ok(result.upload_id)
+    }
+
+    /// Uploads a part of a multipart upload. Parts are numbered from 1, and all of them but the
+    /// last must be at least 5 MiB and of the same size.
+    pub async fn upload_part(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        part_number: u16,
+        body: impl Into<Bytes>,
+    ) -> Result<CompletedPart, ObjectsError> {
+        let response = self
+            .send(
+                Method::PUT,
+                bucket,
+                Some(key),
+                &[
+                    ("partNumber", part_number.to_string()),
+                    ("uploadId", upload_id.to_string()),
+                ],
+                HeaderMap::new(),
+                Some(body.into().into()),
+            )
+            .await?;
+        let etag = ObjectHead::from_headers(response.headers())
+            .etag
+            .ok_or(ObjectsError::MissingETag(part_number))?;
+        Ok(CompletedPart { part_number, etag })
+    }
+
+    /// Assembles the uploaded parts into the object. Returns its ETag.
+    pub async fn complete_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+        parts: &[CompletedPart],
+    ) -> Result<Option<String>, ObjectsError> {
+        #[derive(Serialize)]
+        #[serde(rename = "CompleteMultipartUpload")]
+        struct CompleteMultipartUpload<'a> {
+            #[serde(rename = "Part")]
+            parts: &'a [CompletedPart],
+        }
+
+        let body = quick_xml::se::to_string(&CompleteMultipartUpload { parts })
+            .expect("Failed to serialize parts");
+        let response = self
+            .send(
+                Method::POST,
+                bucket,
+                Some(key),
+                &[("uploadId", upload_id.to_string())],
+                xml_headers(),
+                Some(body.into()),
+            )
+            .await?;
+        let result: CompleteMultipartUploadResult = parse_xml(response).await?;
+        Ok(result.etag)
+    }
+
+    /// Aborts a multipart upload, discarding its parts.
+    pub async fn abort_multipart_upload(
+        &self,
+        bucket: &str,
+        key: &str,
+        upload_id: &str,
+    ) -> Result<(), ObjectsError> {
+        self.send(
+            Method::DELETE,
+            bucket,
+            Some(key),
+            &[("uploadId", upload_id.to_string())],
+            HeaderMap::new(),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// A URL allowing anyone holding it to read (`GET`) or upload (`PUT`) an object for
+    /// `expires_in`, without credentials, e.g. to let a browser upload a file directly.
+    /// Generated locally; `expires_in` must be between one second and seven days, and `key` can't
+    /// have a `.` or `..` segment.
+    pub fn presigned_url(
+        &self,
+        method: PresignMethod,
+        bucket: &str,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<Url, ObjectsError> {
+        self.presigned_url_at(method, bucket, key, expires_in, Utc::now())
+    }
+
+    fn presigned_url_at(
+        &self,
+        method: PresignMethod,
+        bucket: &str,
+        key: &str,
+        expires_in: Duration,
+        now: DateTime<Utc>,
+    ) -> Result<Url, ObjectsError> {
+        let seconds = expires_in.as_secs();
+        if !(1..=MAX_PRESIGNED_EXPIRY.as_secs()).contains(&seconds) {
+            return Err(ObjectsError::InvalidExpiry(expires_in));
+        }
+        let method = match method {
+            PresignMethod::Get => Method::GET,
+            PresignMethod::Put => Method::PUT,
+        };
+        let mut url = self.url(bucket, Some(key), &[])?;
+        sigv4::presign(
+            method.as_str(),
+            &mut url,
+            &self.credentials,
+            sigv4::REGION,
+            &now,
+            seconds,
+        );
+        Ok(url)
+    }
+
+    async fn get(
+        &self,
+        bucket: &str,
+        key: &str,
+        range: Option<ByteRange>,
+    ) -> Result<reqwest::Response, ObjectsError> {
+        let mut headers = HeaderMap::new();
+        if let Some(range) = range {
+            headers.insert(
+                reqwest::header::RANGE,
+                header_value("range", &range.header_value())?,
+            );
+        }
+        self.send(Method::GET, bucket, Some(key), &[], headers, None)
+            .await
+    }
+
+    /// The URL of an object, or of a bucket when `key` is `None`.
+    fn url(
+        &self,
+        bucket: &str,
+        key: Option<&str>,
+        query: &[(&str, String)],
+    ) -> Result<Url, ObjectsError> {
+        let mut url = self.endpoint.clone();
+        let mut path = format!("/{}", sigv4::uri_encode(bucket, true));
+        if let Some(key) = key {
+            if key
+                .split('/')
+                .any(|segment| segment == "." || segment == "..")
+            {
+                return Err(ObjectsError::UnaddressableKey(key.to_string()));
+            }
+            path.push('/');
+            path.push_str(&sigv4::uri_encode(key, false));
+        }
+        url.set_path(&path);
+        if !query.is_empty() {
+            sigv4::append_query(&mut url, query);
+        }
+        Ok(url)
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        bucket: &str,
+        key: Option<&str>,
+        query: &[(&str, String)],
+        mut headers: HeaderMap,
+        body: Option<reqwest::Body>,
+    ) -> Result<reqwest::Response, ObjectsError> {
+        let url = self.url(bucket, key, query)?;
+        let now = Utc::now();
+        headers.insert(
+            "x-amz-date",
+            header_value("x-amz-date", &sigv4::amz_date(&now))?,
+        );
+        headers.insert(
+            "x-amz-content-sha256",
+            HeaderValue::from_static(UNSIGNED_PAYLOAD),
+        );
+        if let Some(token) = &self.credentials.session_token {
+            headers.insert(
+                "x-amz-security-token",
+                header_value("x-amz-security-token", token)?,
+            );
+        }
+
+        let mut signed_headers = vec![("host".to_string(), sigv4::host(&url))];
+        for (name, value) in &headers {
+            signed_headers.push((
+                name.as_str().to_string(),
+                value.to_str().unwrap_or_default().to_string(),
+            ));
+        }
+        let signature = sigv4::sign(
+            &CanonicalRequest {
+                method: method.as_str(),
+                url: &url,
+                headers: signed_headers,
+                payload_hash: UNSIGNED_PAYLOAD,
+            },
+            &self.credentials,
+            sigv4::REGION,
+            &now,
+        );
+        headers.insert(
+            reqwest::header::AUTHORIZATION,
+            header_value("authorization", &signature.authorization(&self.credentials))?,
+        );
+
+        let mut request = self.http_client.request(method, url).headers(headers);
+        if let Some(body) = body {
+            request = request.body(body);
+        }
+        let response = request.send().await?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        let (code, message) = match quick_xml::de::from_str::<ErrorResponse>(&body) {
+            Ok(error) => (error.code, error.message),
+            Err(_) => (
+                status.canonical_reason().unwrap_or_default().to_string(),
+                body,
+            ),
+        };
+        Err(ObjectsError::Api {
+            status,
+            code,
+            message,
+        })
+    }
+}
+
+fn header_value(name: &str, value: &str) -> Result<HeaderValue, ObjectsError> {
+    HeaderValue::from_str(value).map_err(|_| ObjectsError::InvalidHeader(name.to_string()))
+}
+
+fn xml_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        reqwest::header::CONTENT_TYPE,
+        HeaderValue::from_static("application/xml"),
+    );
+    headers
+}
+
+async fn parse_xml<T: serde::de::DeserializeOwned>(
+    response: reqwest::Response,
+) -> Result<T, ObjectsError> {
+    let body = response.text().await?;
+    Ok(quick_xml::de::from_str(&body)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mockito::{Matcher, Server};
+
+    fn client(server: &Server) -> ObjectsClient {
+        ObjectsClient::with_endpoint(
+            Url::parse(&server.url()).unwrap(),
+            R2Credentials {
+                access_key_id: "key-id".into(),
+                secret_access_key: "secret".into(),
+                session_token: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_put_and_get_range() {
+        let mut server = Server::new_async().await;
+        let put = server
+            .mock("PUT", "/bucket/dir/my%20file.txt")
+            .match_header(
+                "authorization",
+                Matcher::Regex(
+                    r"^AWS4-HMAC-SHA256 Credential=key-id/\d{8}/auto/s3/aws4_request, SignedHeaders=content-length;content-type;host;x-amz-content-sha256;x-amz-date;x-amz-meta-owner, Signature=[0-9a-f]{64}$"
+                        .into(),
+                ),
+            )
+            .match_header("x-amz-meta-owner", "me")
+            .match_body("hello world")
+            .with_header("etag", "\"abc\"")
+            .create();
+        let get = server
+            .mock("GET", "/bucket/dir/my%20file.txt")
+            .match_header("range", "bytes=0-4")
+            .with_status(206)
+            .with_header("content-range", "bytes 0-4/11")
+            .with_header("x-amz-meta-owner", "me")
+            .with_body("hello")
+            .create();
+
+        let client = client(&server);
+        let options = PutObjectOptions {
+            content_type: Some("text/plain".into()),
+            metadata: BTreeMap::from([("owner".to_string(), "me".to_string())]),
+            ..Default::default()
+        };
+        let etag = client
+            .put_object(
+                "bucket",
+                "dir/my file.txt",
+                Bytes::from_static(b"hello world"),
+                &options,
+            )
+            .await
+            .unwrap();
+        let object = client
+            .get_object(
+                "bucket",
+                "dir/my file.txt",
+                Some(ByteRange::Inclusive(0, 4)),
+            )
+            .await
+            .unwrap();
+
+        put.assert();
+        get.assert();
+        assert_eq!(etag.as_deref(), Some("\"abc\""));
+        assert_eq!(object.body, "hello");
+        assert_eq!(object.content_range.as_deref(), Some("bytes 0-4/11"));
+        assert_eq!(object.head.metadata["owner"], "me");
+    }
+
+    #[tokio::test]
+    async fn test_list_objects_and_errors() {
+        let mut server = Server::new_async().await;
+        server
+            .mock("GET", "/bucket")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("list-type".into(), "2".into()),
+                Matcher::UrlEncoded("prefix".into(), "logs/".into()),
+            ]))
+            .with_body(
+                r#"<?xml version="1.0" encoding="UTF-8"?>
+                <ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">
+                    <Name>bucket</Name>
+                    <Prefix>logs/</Prefix>
+                    <KeyCount>2</KeyCount>
+                    <MaxKeys>2</MaxKeys>
+                    <IsTruncated>true</IsTruncated>
+                    <NextContinuationToken>next</NextContinuationToken>
+                    <Contents>
+                        <Key>logs/a</Key>
+                        <LastModified>2024-01-01T00:00:00.000Z</LastModified>
+                        <ETag>"a"</ETag>
+                        <Size>1</Size>
+                        <StorageClass>STANDARD</StorageClass>
+                    </Contents>
+                    <Contents>
+                        <Key>logs/b</Key>
+                        <Size>2</Size>
+                    </Contents>
+                </ListBucketResult>"#,
+            )
+            .create();
+        server
+            .mock("HEAD", "/bucket/missing")
+            .with_status(404)
+            .create();
+
+        let client = client(&server);
+        let page = client
+            .list_objects_v2(
+                "bucket",
+                &ListObjectsV2Params {
+                    prefix: Some("logs/".into()),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let error = client.head_object("bucket", "missing").await.unwrap_err();
+
+        assert!(page.is_truncated);
+        assert_eq!(page.next_continuation_token.as_deref(), Some("next"));
+        let keys: Vec<&str> = page.contents.iter().map(|o| o.key.as_str()).collect();
+        assert_eq!(keys, vec!["logs/a", "logs/b"]);
+        assert_eq!(page.contents[1].size, 2);
+        assert!(error.is_not_found());
+    }
+
+    #[tokio::test]
+    async fn test_streamed_put_and_missing_etag() {
+        let mut server = Server::new_async().await;
+        let put = server
+            .mock("PUT", "/bucket/big")
+            .match_header("content-length", "16")
+            .match_body("streamed content")
+            .with_header("etag", "\"abc\"")
+            .create();
+        let part = server
+            .mock("PUT", "/bucket/big")
+            .match_query(Matcher::UrlEncoded("partNumber".into(), "1".into()))
+            .create();
+
+        let client = client(&server);
+        let body =
+            StreamBody::from_reader(io::Cursor::new(b"streamed content".to_vec())).with_length(16);
+        let etag = client
+            .put_object("bucket", "big", body, &PutObjectOptions::default())
+            .await
+            .unwrap();
+        let error = client
+            .upload_part("bucket", "big", "upload", 1, "part")
+            .await
+            .unwrap_err();
+
+        put.assert();
+        part.assert();
+        assert_eq!(etag.as_deref(), Some("\"abc\""));
+        assert!(matches!(error, ObjectsError::MissingETag(1)));
+    }
+
+    #[tokio::test]
+    async fn test_dot_segment_keys_are_rejected() {
+        let mut server = Server::new_async().await;
+        // Where the URL of `a/../b` resolves to, which must be left alone.
+        let resolved_put = server.mock("PUT", "/bucket/b").expect(0).create();
+        let resolved_delete = server.mock("DELETE", "/bucket/b").expect(0).create();
+        let dotted = server
+            .mock("PUT", "/bucket/a/..b/c.")
+            .with_header("etag", "\"abc\"")
+            .create();
+
+        let client = client(&server);
+        let put = client
+            .put_object(
+                "bucket",
+                "a/../b",
+                Bytes::from_static(b"data"),
+                &PutObjectOptions::default(),
+            )
+            .await;
+        let delete = client.delete_object("bucket", "a/../b").await;
+        let get = client.get_object("bucket", "./b", None).await;
+        // Dots within a segment are fine.
+        let etag = client
+            .put_object(
+                "bucket",
+                "a/..b/c.",
+                Bytes::new(),
+                &PutObjectOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        resolved_put.assert();
+        resolved_delete.assert();
+        dotted.assert();
+        assert!(matches!(put, Err(ObjectsError::UnaddressableKey(key)) if key == "a/../b"));
+        assert!(matches!(delete, Err(ObjectsError::UnaddressableKey(_))));
+        assert!(matches!(get, Err(ObjectsError::UnaddressableKey(_))));
+        assert_eq!(etag.as_deref(), Some("\"abc\""));
+    }
+
+    #[test]
+    fn test_presigned_url() {
+        let client = ObjectsClient::new(
+            "account",
+            None,
+            R2Credentials {
+                access_key_id: "key-id".into(),
+                secret_access_key: "secret".into(),
+                session_token: Some("token/+=".into()),
+            },
+        )
+        .unwrap();
+        let now = DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let url = client
+            .presigned_url_at(
+                PresignMethod::Put,
+                "uploads",
+                "user 1/avatar.png",
+                Duration::from_secs(900),
+                now,
+            )
+            .unwrap();
+        let (base, query) = url.as_str().split_once('?').unwrap();
+        assert_eq!(
+            base,
+            "https://account.r2.cloudflarestorage.com/uploads/user%201/avatar.png"
+        );
+        let params: Vec<&str> = query.split('&').collect();
+        assert_eq!(
+            params[..6],
+            [
+                "X-Amz-Algorithm=AWS4-HMAC-SHA256",
+                "X-Amz-Credential=key-id%2F20240102%2Fauto%2Fs3%2Faws4_request",
+                "X-Amz-Date=20240102T030405Z",
+                "X-Amz-Expires=900",
+                "X-Amz-SignedHeaders=host",
+                "X-Amz-Security-Token=token%2F%2B%3D",
+            ]
+        );
+        assert!(params[6].starts_with("X-Amz-Signature="));
+
+        assert!(matches!(
+            ObjectsClient::new("account/path", None, client.credentials.clone()),
+            Err(ObjectsError::InvalidAccountIdentifier(_))
+        ));
+
+        assert!(matches!(
+            client.presigned_url_at(
+                PresignMethod::Get,
+                "uploads",
+                "a",
+                MAX_PRESIGNED_EXPIRY + Duration::from_secs(1),
+                now,
+            ),
+            Err(ObjectsError::InvalidExpiry(_))
+        ));
+        // The URL of `a/../b` would grant access to the key `b`.
+        assert!(matches!(
+            client.presigned_url_at(
+                PresignMethod::Get,
+                "uploads",
+                "a/../b",
+                Duration::from_secs(900),
+                now,
+            ),
+            Err(ObjectsError::UnaddressableKey(key)) if key == "a/../b"
+        ));
+    }
+}
@@ -1,18 +1,26 @@
 use chrono::offset::Utc;
 use chrono::DateTime;
+use http::{HeaderMap, HeaderValue};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 
-use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody};
 use crate::framework::response::{ApiResult, ApiSuccess};
+use crate::framework::OrderDirection;
 
 /// A Bucket is a collection of Objects stored in R2.
+#[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct Bucket {
     /// Bucket name
     pub name: String,
     /// Creation date of the bucket
     pub creation_date: DateTime<Utc>,
+    /// Location of the bucket
+    pub location: Option<LocationHint>,
+    /// Jurisdiction of the bucket
+    pub jurisdiction: Option<Jurisdiction>,
+    /// Storage class for newly uploaded objects, unless specified otherwise.
+    pub storage_class: Option<StorageClass>,
 }
 
 /// ListBucketsResult contains a list of buckets in an account.
@@ -21,14 +29,91 @@ pub struct ListBucketsResult {
     pub buckets: Vec<Bucket>,
 }
 
-type EmptyMap = HashMap<(), ()>;
-impl ApiResult for EmptyMap {}
+/// The empty object returned by R2 endpoints that have nothing to return.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmptyResult {}
+
+impl ApiResult for Bucket {}
 impl ApiResult for ListBucketsResult {}
+impl ApiResult for EmptyResult {}
+
+/// The region a bucket's data is placed in, when it is created.
+/// <https://developers.cloudflare.com/r2/reference/data-location/#location-hints>
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LocationHint {
+    /// Asia-Pacific
+    #[serde(alias = "APAC")]
+    Apac,
+    /// Eastern Europe
+    #[serde(alias = "EEUR")]
+    Eeur,
+    /// Eastern North America
+    #[serde(alias = "ENAM")]
+    Enam,
+    /// Western Europe
+    #[serde(alias = "WEUR")]
+    Weur,
+    /// Western North America
+    #[serde(alias = "WNAM")]
+    Wnam,
+    /// Oceania
+    #[serde(alias = "OC")]
+    Oc,
+}
+
+/// The jurisdiction a bucket's data is guaranteed to be stored within.
+///
+/// Buckets created in a jurisdiction can only be reached by passing that jurisdiction to every
+/// request about them.
+/// <https://developers.cloudflare.com/r2/reference/data-location/#jurisdictional-restrictions>
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Jurisdiction {
+    Default,
+    /// The European Union
+    Eu,
+    /// FedRAMP-compliant data centers
+    Fedramp,
+}
+
+impl Jurisdiction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Jurisdiction::Default => "default",
+            Jurisdiction::Eu => "eu",
+            Jurisdiction::Fedramp => "fedramp",
+        }
+    }
+}
+
+/// Sets the `cf-r2-jurisdiction` header from an optional jurisdiction.
+pub(crate) fn jurisdiction_headers(jurisdiction: Option<Jurisdiction>) -> Option<HeaderMap> {
+    let jurisdiction = jurisdiction?;
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        "cf-r2-jurisdiction",
+        HeaderValue::from_static(jurisdiction.as_str()),
+    );
+    Some(headers)
+}
+
+/// The storage class of an object.
+/// <https://developers.cloudflare.com/r2/buckets/storage-classes/>
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StorageClass {
+    Standard,
+    InfrequentAccess,
+}
 
 /// Lists all buckets within the account.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/methods/list/>
 #[derive(Debug)]
 pub struct ListBuckets<'a> {
     pub account_identifier: &'a str,
+    pub params: ListBucketsParams,
+    /// Lists the buckets of this jurisdiction instead of the default one.
+    pub jurisdiction: Option<Jurisdiction>,
 }
 
 impl EndpointSpec for ListBuckets<'_> {
@@ -41,41 +126,132 @@ impl EndpointSpec for ListBuckets<'_> {
     fn path(&self) -> String {
         format!("accounts/{}/r2/buckets", self.account_identifier)
     }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+/// Filters and pagination for [`ListBuckets`].
+///
+/// The cursor of the next page, if any, is in the `cursor` field of the response's `result_info`.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ListBucketsParams {
+    /// Only list buckets whose name contains this string.
+    pub name_contains: Option<String>,
+    /// Only list buckets whose name comes after this one, in lexicographic order.
+    pub start_after: Option<String>,
+    /// Maximum number of buckets to return in a page, up to 1000.
+    pub per_page: Option<u32>,
+    /// Field to order buckets by. Only `name` is supported.
+    pub order: Option<String>,
+    pub direction: Option<OrderDirection>,
+    /// Cursor of the page to return, from a previous response.
+    pub cursor: Option<String>,
+}
+
+/// Gets the properties of an existing bucket.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/methods/get/>
+#[derive(Debug)]
+pub struct GetBucket<'a> {
+    pub account_identifier: &'a str,
+    pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+impl EndpointSpec for GetBucket<'_> {
+    type JsonResponse = Bucket;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/buckets/{}",
+            self.account_identifier, self.bucket_name
+        )
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
 }
 
 /// Creates a bucket with the given name.
 /// A 400 is returned if the account already owns a bucket with this name.
 /// A bucket must be explicitly deleted to be replaced.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/methods/create/>
 #[derive(Debug)]
 pub struct CreateBucket<'a> {
     pub account_identifier: &'a str,
     pub bucket_name: &'a str,
+    pub params: CreateBucketParams,
+    /// Creates the bucket in this jurisdiction. It can't be changed later.
+    pub jurisdiction: Option<Jurisdiction>,
 }
 
 impl EndpointSpec for CreateBucket<'_> {
-    type JsonResponse = EmptyMap;
+    type JsonResponse = Bucket;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
 
     fn method(&self) -> Method {
-        Method::PUT
+        Method::POST
     }
     fn path(&self) -> String {
-        format!(
-            "accounts/{}/r2/buckets/{}",
-            self.account_identifier, self.bucket_name
-        )
+        format!("accounts/{}/r2/buckets", self.account_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        #[derive(Serialize)]
+        struct Body<'a> {
+            name: &'a str,
+            #[serde(flatten)]
+            params: &'a CreateBucketParams,
+        }
+
+        let body = serde_json::to_string(&Body {
+            name: self.bucket_name,
+            params: &self.params,
+        })
+        .unwrap();
+        Some(RequestBody::Json(body))
+    }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
     }
 }
 
-/// Deletes a bucket with the given name.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBucketParams {
+    /// The region to place the bucket's data in. Defaults to the region closest to the request.
+    pub location_hint: Option<LocationHint>,
+    /// Storage class for newly uploaded objects, unless specified otherwise.
+    /// Defaults to `Standard`.
+    pub storage_class: Option<StorageClass>,
+}
+
+/// Deletes a bucket with the given name. The bucket must be empty.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/buckets/methods/delete/>
 #[derive(Debug)]
 pub struct DeleteBucket<'a> {
     pub account_identifier: &'a str,
     pub bucket_name: &'a str,
+    /// The jurisdiction the bucket was created in, if any.
+    pub jurisdiction: Option<Jurisdiction>,
 }
 
 impl EndpointSpec for DeleteBucket<'_> {
-    type JsonResponse = EmptyMap;
+    type JsonResponse = EmptyResult;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
 
     fn method(&self) -> Method {
@@ -87,4 +263,73 @@ impl EndpointSpec for DeleteBucket<'_> {
             self.account_identifier, self.bucket_name
         )
     }
+    #[inline]
+    fn headers(&self) -> Option<HeaderMap> {
+        jurisdiction_headers(self.jurisdiction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_create_bucket_in_jurisdiction() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("POST", "/accounts/acc/r2/buckets")
+            .match_header("cf-r2-jurisdiction", "eu")
+            .match_body(Matcher::Json(json!({
+                "name": "my-bucket",
+                "locationHint": "weur",
+                "storageClass": "InfrequentAccess"
+            })))
+            .with_body(
+                json!({
+                    "result": {
+                        "name": "my-bucket",
+                        "creation_date": "2024-01-01T00:00:00Z",
+                        "location": "WEUR",
+                        "jurisdiction": "eu",
+                        "storage_class": "InfrequentAccess"
+                    },
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let bucket = client
+            .request(&CreateBucket {
+                account_identifier: "acc",
+                bucket_name: "my-bucket",
+                params: CreateBucketParams {
+                    location_hint: Some(LocationHint::Weur),
+                    storage_class: Some(StorageClass::InfrequentAccess),
+                },
+                jurisdiction: Some(Jurisdiction::Eu),
+            })
+            .await
+            .unwrap()
+            .result;
+
+        mock.assert();
+        assert_eq!(bucket.location, Some(LocationHint::Weur));
+        assert_eq!(bucket.jurisdiction, Some(Jurisdiction::Eu));
+        assert_eq!(bucket.storage_class, Some(StorageClass::InfrequentAccess));
+    }
 }
@@ -0,0 +1,498 @@
+use super::r2::Jurisdiction;
+use super::{GcsCredentials, S3Credentials};
+
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A Super Slurper job, copying every object of a bucket into R2.
+/// <https://developers.cloudflare.com/r2/data-migration/super-slurper/>
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationJob {
+    pub id: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Whether objects already in the target bucket are overwritten.
+    pub overwrite: Option<bool>,
+    pub source: Option<MigrationJobBucket>,
+    pub target: Option<MigrationJobBucket>,
+    pub status: Option<MigrationJobStatus>,
+}
+
+/// A bucket a job copies from or to, without its credentials.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationJobBucket {
+    pub bucket: Option<String>,
+    pub vendor: Option<MigrationVendor>,
+    /// The S3-compatible endpoint of the source bucket, if not AWS.
+    pub endpoint: Option<String>,
+    /// Only objects whose key starts with this prefix are copied.
+    pub path_prefix: Option<String>,
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationVendor {
+    /// Amazon S3, or another S3-compatible store.
+    S3,
+    /// Google Cloud Storage
+    Gcs,
+    R2,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationJobStatus {
+    Running,
+    Paused,
+    Aborted,
+    Completed,
+}
+
+/// How far along a job is.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationJobProgress {
+    pub id: String,
+    pub created_at: Option<DateTime<Utc>>,
+    pub status: Option<MigrationJobStatus>,
+    /// The number of objects found in the source bucket so far.
+    #[serde(default)]
+    pub objects: u64,
+    #[serde(default)]
+    pub transferred_objects: u64,
+    #[serde(default)]
+    pub skipped_objects: u64,
+    #[serde(default)]
+    pub failed_objects: u64,
+}
+
+/// An event in the life of a job, e.g. an object that couldn't be copied.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationJobLog {
+    pub created_at: Option<DateTime<Utc>>,
+    /// ID of the job.
+    pub job: Option<String>,
+    pub log_type: MigrationLogType,
+    pub message: Option<String>,
+    /// The object the event is about, if any.
+    pub object_key: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum MigrationLogType {
+    MigrationStart,
+    MigrationComplete,
+    MigrationAbort,
+    MigrationError,
+    MigrationPause,
+    MigrationResume,
+    MigrationErrorFailedContinuation,
+    ImportErrorRetryExhaustion,
+    ImportSkippedStorageClass,
+    ImportSkippedOversized,
+    ImportSkippedEmptyObject,
+    ImportSkippedUnsupportedContentType,
+    ImportSkippedExcludedContentType,
+    ImportSkippedInvalidMedia,
+    ImportSkippedRequiresRetrieval,
+    /// A log type added to the API after this version of the library.
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct CreatedMigrationJob {
+    pub id: String,
+}
+
+impl ApiResult for MigrationJob {}
+impl ApiResult for Vec<MigrationJob> {}
+impl ApiResult for MigrationJobProgress {}
+impl ApiResult for Vec<MigrationJobLog> {}
+impl ApiResult for CreatedMigrationJob {}
+
+/// Creates a Super Slurper job, which starts copying objects right away.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/create/>
+#[derive(Debug)]
+pub struct CreateMigrationJob<'a> {
+    pub account_identifier: &'a str,
+    pub params: CreateMigrationJobParams,
+}
+
+impl EndpointSpec for CreateMigrationJob<'_> {
+    type JsonResponse = CreatedMigrationJob;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/slurper/jobs", self.account_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct CreateMigrationJobParams {
+    pub source: MigrationSource,
+    pub target: MigrationTarget,
+    /// Whether to overwrite objects already in the target bucket. Defaults to `true`.
+    pub overwrite: Option<bool>,
+}
+
+/// The bucket to copy objects from, and how to read it.
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "vendor", rename_all = "lowercase")]
+pub enum MigrationSource {
+    /// Amazon S3, or another S3-compatible store when `endpoint` is set.
+    S3 {
+        bucket: String,
+        secret: S3Credentials,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        endpoint: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        region: Option<String>,
+        #[serde(rename = "pathPrefix", skip_serializing_if = "Option::is_none")]
+        path_prefix: Option<String>,
+    },
+    /// Google Cloud Storage
+    Gcs {
+        bucket: String,
+        secret: GcsCredentials,
+        #[serde(rename = "pathPrefix", skip_serializing_if = "Option::is_none")]
+        path_prefix: Option<String>,
+    },
+    /// Another R2 bucket.
+    R2 {
+        bucket: String,
+        secret: S3Credentials,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        jurisdiction: Option<Jurisdiction>,
+        #[serde(rename = "pathPrefix", skip_serializing_if = "Option::is_none")]
+        path_prefix: Option<String>,
+    },
+}
+
+/// The R2 bucket to copy objects to, and an access key with write access to it.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "vendor", rename = "r2")]
+pub struct MigrationTarget {
+    pub bucket: String,
+    pub secret: S3Credentials,
+    pub jurisdiction: Option<Jurisdiction>,
+}
+
+/// Lists the Super Slurper jobs of an account.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/list/>
+#[derive(Debug)]
+pub struct ListMigrationJobs<'a> {
+    pub account_identifier: &'a str,
+    pub params: MigrationPageParams,
+}
+
+impl EndpointSpec for ListMigrationJobs<'_> {
+    type JsonResponse = Vec<MigrationJob>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/slurper/jobs", self.account_identifier)
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct MigrationPageParams {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// Gets a Super Slurper job.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/get/>
+#[derive(Debug)]
+pub struct GetMigrationJob<'a> {
+    pub account_identifier: &'a str,
+    pub job_identifier: &'a str,
+}
+
+impl EndpointSpec for GetMigrationJob<'_> {
+    type JsonResponse = MigrationJob;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/slurper/jobs/{}",
+            self.account_identifier, self.job_identifier
+        )
+    }
+}
+
+/// Pauses a running Super Slurper job.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/pause/>
+#[derive(Debug)]
+pub struct PauseMigrationJob<'a> {
+    pub account_identifier: &'a str,
+    pub job_identifier: &'a str,
+}
+
+impl EndpointSpec for PauseMigrationJob<'_> {
+    type JsonResponse = String;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/slurper/jobs/{}/pause",
+            self.account_identifier, self.job_identifier
+        )
+    }
+}
+
+/// Resumes a paused Super Slurper job.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/resume/>
+#[derive(Debug)]
+pub struct ResumeMigrationJob<'a> {
+    pub account_identifier: &'a str,
+    pub job_identifier: &'a str,
+}
+
+impl EndpointSpec for ResumeMigrationJob<'_> {
+    type JsonResponse = String;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/slurper/jobs/{}/resume",
+            self.account_identifier, self.job_identifier
+        )
+    }
+}
+
+/// Aborts a Super Slurper job. An aborted job can't be resumed.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/abort/>
+#[derive(Debug)]
+pub struct AbortMigrationJob<'a> {
+    pub account_identifier: &'a str,
+    pub job_identifier: &'a str,
+}
+
+impl EndpointSpec for AbortMigrationJob<'_> {
+    type JsonResponse = String;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/slurper/jobs/{}/abort",
+            self.account_identifier, self.job_identifier
+        )
+    }
+}
+
+/// Aborts every Super Slurper job of an account.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/abort_all/>
+#[derive(Debug)]
+pub struct AbortAllMigrationJobs<'a> {
+    pub account_identifier: &'a str,
+}
+
+impl EndpointSpec for AbortAllMigrationJobs<'_> {
+    type JsonResponse = String;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/slurper/jobs/abortAll", self.account_identifier)
+    }
+}
+
+/// Gets the object counts of a Super Slurper job.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/methods/progress/>
+#[derive(Debug)]
+pub struct GetMigrationJobProgress<'a> {
+    pub account_identifier: &'a str,
+    pub job_identifier: &'a str,
+}
+
+impl EndpointSpec for GetMigrationJobProgress<'_> {
+    type JsonResponse = MigrationJobProgress;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/slurper/jobs/{}/progress",
+            self.account_identifier, self.job_identifier
+        )
+    }
+}
+
+/// Lists the logs of a Super Slurper job, oldest first.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/super_slurper/subresources/jobs/subresources/logs/methods/list/>
+#[derive(Debug)]
+pub struct ListMigrationJobLogs<'a> {
+    pub account_identifier: &'a str,
+    pub job_identifier: &'a str,
+    pub params: MigrationPageParams,
+}
+
+impl EndpointSpec for ListMigrationJobLogs<'_> {
+    type JsonResponse = Vec<MigrationJobLog>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/slurper/jobs/{}/logs",
+            self.account_identifier, self.job_identifier
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_create_job_and_read_logs() {
+        let mut server = Server::new_async().await;
+        let create = server
+            .mock("POST", "/accounts/acc/slurper/jobs")
+            .match_body(Matcher::Json(json!({
+                "source": {
+                    "vendor": "s3",
+                    "bucket": "legacy",
+                    "secret": {"accessKeyId": "aws-id", "secretAccessKey": "aws-secret"},
+                    "region": "us-east-1"
+                },
+                "target": {
+                    "vendor": "r2",
+                    "bucket": "new",
+                    "secret": {"accessKeyId": "r2-id", "secretAccessKey": "r2-secret"}
+                },
+                "overwrite": false
+            })))
+            .with_body(
+                r#"{"success": true, "errors": [], "messages": [], "result": {"id": "job"}}"#,
+            )
+            .create();
+        let list_logs = server
+            .mock("GET", "/accounts/acc/slurper/jobs/job/logs")
+            .match_query(Matcher::Any)
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": [
+                    {"createdAt": "2024-01-01T00:00:00Z", "job": "job", "logType": "migrationStart"},
+                    {"job": "job", "logType": "importSkippedOversized", "objectKey": "huge.bin"},
+                    {"job": "job", "logType": "somethingNew"}
+                ]})
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let job = client
+            .request(&CreateMigrationJob {
+                account_identifier: "acc",
+                params: CreateMigrationJobParams {
+                    source: MigrationSource::S3 {
+                        bucket: "legacy".into(),
+                        secret: S3Credentials {
+                            access_key_id: "aws-id".into(),
+                            secret_access_key: "aws-secret".into(),
+                        },
+                        endpoint: None,
+                        region: Some("us-east-1".into()),
+                        path_prefix: None,
+                    },
+                    target: MigrationTarget {
+                        bucket: "new".into(),
+                        secret: S3Credentials {
+                            access_key_id: "r2-id".into(),
+                            secret_access_key: "r2-secret".into(),
+                        },
+                        jurisdiction: None,
+                    },
+                    overwrite: Some(false),
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+        let logs = client
+            .request(&ListMigrationJobLogs {
+                account_identifier: "acc",
+                job_identifier: &job.id,
+                params: MigrationPageParams::default(),
+            })
+            .await
+            .unwrap()
+            .result;
+
+        create.assert();
+        list_logs.assert();
+        assert_eq!(
+            logs.iter().map(|l| l.log_type).collect::<Vec<_>>(),
+            [
+                MigrationLogType::MigrationStart,
+                MigrationLogType::ImportSkippedOversized,
+                MigrationLogType::Unknown,
+            ]
+        );
+        assert_eq!(logs[1].object_key.as_deref(), Some("huge.bin"));
+    }
+}
@@ -0,0 +1,87 @@
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::{Deserialize, Serialize};
+
+/// What temporary credentials are allowed to do.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TempAccessPermission {
+    /// Read and write the bucket's objects and configuration.
+    AdminReadWrite,
+    /// Read the bucket's objects and configuration.
+    AdminReadOnly,
+    /// Read and write the bucket's objects.
+    ObjectReadWrite,
+    /// Read the bucket's objects.
+    ObjectReadOnly,
+}
+
+/// Short-lived credentials for the S3-compatible API, derived from an R2 access key.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TempAccessCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// Must be sent along the access key, e.g. as the `X-Amz-Security-Token` header.
+    pub session_token: String,
+}
+
+impl ApiResult for TempAccessCredentials {}
+
+#[cfg(feature = "r2-objects")]
+impl From<TempAccessCredentials> for super::objects::R2Credentials {
+    fn from(credentials: TempAccessCredentials) -> Self {
+        super::objects::R2Credentials {
+            access_key_id: credentials.access_key_id,
+            secret_access_key: credentials.secret_access_key,
+            session_token: Some(credentials.session_token),
+        }
+    }
+}
+
+/// Creates temporary credentials scoped to a bucket, and optionally to some of its objects.
+/// <https://developers.cloudflare.com/api/resources/r2/subresources/temporary_credentials/methods/create/>
+#[derive(Debug)]
+pub struct CreateTempAccessCredentials<'a> {
+    pub account_identifier: &'a str,
+    pub params: CreateTempAccessCredentialsParams,
+}
+
+impl EndpointSpec for CreateTempAccessCredentials<'_> {
+    type JsonResponse = TempAccessCredentials;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/r2/temp-access-credentials",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTempAccessCredentialsParams {
+    /// Name of the bucket the credentials can access.
+    pub bucket: String,
+    /// The R2 access key the credentials are derived from. Its permissions must be a superset of
+    /// `permission`.
+    pub parent_access_key_id: String,
+    pub permission: TempAccessPermission,
+    /// How long the credentials are valid for, in seconds.
+    pub ttl_seconds: u64,
+    /// Restricts the credentials to these object keys.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub objects: Vec<String>,
+    /// Restricts the credentials to the objects whose key starts with one of these prefixes.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub prefixes: Vec<String>,
+}
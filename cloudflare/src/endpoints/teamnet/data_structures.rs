@@ -0,0 +1,47 @@
+use chrono::{offset::Utc, DateTime};
+use ipnetwork::IpNetwork;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::endpoints::cfd_tunnel::TunnelType;
+use crate::framework::response::ApiResult;
+
+/// A route sending the traffic for a private network to a tunnel.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub id: Uuid,
+    /// The private network, in CIDR notation.
+    pub network: IpNetwork,
+    pub tunnel_id: Uuid,
+    /// The virtual network the route belongs to. The account's default virtual network if not
+    /// set when creating it.
+    pub virtual_network_id: Option<Uuid>,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Only set when listing routes or getting a route by IP.
+    pub tun_type: Option<TunnelType>,
+    /// Only set when listing routes or getting a route by IP.
+    pub tunnel_name: Option<String>,
+    /// Only set when listing routes or getting a route by IP.
+    pub virtual_network_name: Option<String>,
+}
+
+/// A virtual network, so that routes for the same private network can point to different tunnels.
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct VirtualNetwork {
+    pub id: Uuid,
+    pub name: String,
+    pub comment: Option<String>,
+    /// Whether routes created without a virtual network belong to this one.
+    pub is_default_network: bool,
+    pub created_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl ApiResult for Route {}
+impl ApiResult for Vec<Route> {}
+impl ApiResult for VirtualNetwork {}
+impl ApiResult for Vec<VirtualNetwork> {}
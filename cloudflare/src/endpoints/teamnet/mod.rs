@@ -0,0 +1,10 @@
+//! Zero Trust private networks: the IP routes sending traffic from WARP clients to tunnels, and
+//! the virtual networks isolating overlapping routes from each other.
+//! <https://developers.cloudflare.com/cloudflare-one/connections/connect-networks/private-net/>
+
+mod data_structures;
+pub mod routes;
+pub mod virtual_networks;
+
+pub use data_structures::*;
+pub use ipnetwork::IpNetwork;
@@ -0,0 +1,310 @@
+use super::Route;
+use chrono::{DateTime, Utc};
+use ipnetwork::IpNetwork;
+use serde::Serialize;
+use std::net::IpAddr;
+use uuid::Uuid;
+
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Create a tunnel route
+/// Routes the traffic for a private network to a tunnel, like `cloudflared tunnel route ip add`.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/routes/methods/create/>
+#[derive(Debug)]
+pub struct CreateRoute<'a> {
+    pub account_identifier: &'a str,
+    pub params: CreateRouteParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct CreateRouteParams<'a> {
+    /// The private network, in CIDR notation.
+    pub network: IpNetwork,
+    pub tunnel_id: Uuid,
+    /// The account's default virtual network if not set.
+    pub virtual_network_id: Option<Uuid>,
+    pub comment: Option<&'a str>,
+}
+
+impl EndpointSpec for CreateRoute<'_> {
+    type JsonResponse = Route;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/teamnet/routes", self.account_identifier)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// List tunnel routes
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/routes/methods/list/>
+#[derive(Debug)]
+pub struct ListRoutes<'a> {
+    pub account_identifier: &'a str,
+    pub params: ListRoutesParams<'a>,
+}
+
+/// Filters for the listed routes.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ListRoutesParams<'a> {
+    pub route_id: Option<Uuid>,
+    pub tunnel_id: Option<Uuid>,
+    pub virtual_network_id: Option<Uuid>,
+    /// Only routes for networks contained in this one.
+    pub network_subset: Option<IpNetwork>,
+    /// Only routes for networks containing this one.
+    pub network_superset: Option<IpNetwork>,
+    /// Comma-separated tunnel types, e.g. `cfd_tunnel,warp_connector`.
+    pub tun_types: Option<&'a str>,
+    pub comment: Option<&'a str>,
+    pub is_deleted: Option<bool>,
+    pub existed_at: Option<DateTime<Utc>>,
+    pub page: Option<u32>,
+    pub per_page: Option<u32>,
+}
+
+impl EndpointSpec for ListRoutes<'_> {
+    type JsonResponse = Vec<Route>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!("accounts/{}/teamnet/routes", self.account_identifier)
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+/// Get a tunnel route
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/routes/methods/get/>
+#[derive(Debug)]
+pub struct RouteDetails<'a> {
+    pub account_identifier: &'a str,
+    pub route_id: &'a str,
+}
+
+impl EndpointSpec for RouteDetails<'_> {
+    type JsonResponse = Route;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/routes/{}",
+            self.account_identifier, self.route_id
+        )
+    }
+}
+
+/// Get tunnel route by IP
+/// The most specific route whose network contains the IP, i.e. where its traffic goes.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/routes/subresources/ips/methods/get/>
+#[derive(Debug)]
+pub struct RouteByIp<'a> {
+    pub account_identifier: &'a str,
+    pub ip: IpAddr,
+    /// The account's default virtual network if not set.
+    pub virtual_network_id: Option<Uuid>,
+}
+
+impl EndpointSpec for RouteByIp<'_> {
+    type JsonResponse = Route;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/routes/ip/{}",
+            self.account_identifier, self.ip
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        #[serde_with::skip_serializing_none]
+        #[derive(Serialize)]
+        struct Query {
+            virtual_network_id: Option<Uuid>,
+        }
+
+        serialize_query(&Query {
+            virtual_network_id: self.virtual_network_id,
+        })
+    }
+}
+
+/// Update a tunnel route
+/// Only the given fields are changed.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/routes/methods/edit/>
+#[derive(Debug)]
+pub struct UpdateRoute<'a> {
+    pub account_identifier: &'a str,
+    pub route_id: &'a str,
+    pub params: UpdateRouteParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct UpdateRouteParams<'a> {
+    pub network: Option<IpNetwork>,
+    pub tunnel_id: Option<Uuid>,
+    pub virtual_network_id: Option<Uuid>,
+    pub comment: Option<&'a str>,
+}
+
+impl EndpointSpec for UpdateRoute<'_> {
+    type JsonResponse = Route;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/routes/{}",
+            self.account_identifier, self.route_id
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Delete a tunnel route
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/routes/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteRoute<'a> {
+    pub account_identifier: &'a str,
+    pub route_id: &'a str,
+}
+
+impl EndpointSpec for DeleteRoute<'_> {
+    type JsonResponse = Route;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/routes/{}",
+            self.account_identifier, self.route_id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::cfd_tunnel::TunnelType;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    const TUNNEL: &str = "f70ff985-a4ef-4643-bbbc-4a0ed4fc8415";
+    const VNET: &str = "f1c4f1a2-5b2e-4b5f-9e2a-6c8d1e3b7a90";
+
+    fn route() -> serde_json::Value {
+        json!({
+            "id": "e2b5c0e1-1f4c-4d8e-9a2b-3c4d5e6f7a8b",
+            "network": "172.16.0.0/16",
+            "tunnel_id": TUNNEL,
+            "virtual_network_id": VNET,
+            "comment": "staging",
+            "created_at": "2024-03-01T00:00:00Z",
+            "deleted_at": null,
+            "tun_type": "cfd_tunnel",
+            "tunnel_name": "staging-tunnel",
+            "virtual_network_name": "staging"
+        })
+    }
+
+    #[tokio::test]
+    async fn test_route_private_network() {
+        let mut server = Server::new_async().await;
+        let create = server
+            .mock("POST", "/accounts/acc/teamnet/routes")
+            .match_body(Matcher::Json(json!({
+                "network": "172.16.0.0/16",
+                "tunnel_id": TUNNEL,
+                "virtual_network_id": VNET,
+                "comment": "staging"
+            })))
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": route()})
+                    .to_string(),
+            )
+            .create();
+        let by_ip = server
+            .mock("GET", "/accounts/acc/teamnet/routes/ip/172.16.4.2")
+            .match_query(Matcher::UrlEncoded(
+                "virtual_network_id".into(),
+                VNET.into(),
+            ))
+            .with_body(
+                json!({"success": true, "errors": [], "messages": [], "result": route()})
+                    .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let vnet: Uuid = VNET.parse().unwrap();
+        let created = client
+            .request(&CreateRoute {
+                account_identifier: "acc",
+                params: CreateRouteParams {
+                    network: "172.16.0.0/16".parse().unwrap(),
+                    tunnel_id: TUNNEL.parse().unwrap(),
+                    virtual_network_id: Some(vnet),
+                    comment: Some("staging"),
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+        create.assert();
+
+        let found = client
+            .request(&RouteByIp {
+                account_identifier: "acc",
+                ip: "172.16.4.2".parse().unwrap(),
+                virtual_network_id: Some(vnet),
+            })
+            .await
+            .unwrap()
+            .result;
+        by_ip.assert();
+        assert_eq!(found, created);
+        assert!(found.network.contains("172.16.4.2".parse().unwrap()));
+        assert_eq!(found.tun_type, Some(TunnelType::CfdTunnel));
+    }
+}
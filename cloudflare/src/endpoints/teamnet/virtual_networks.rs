@@ -0,0 +1,165 @@
+use super::VirtualNetwork;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Create a virtual network
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/virtual_networks/methods/create/>
+#[derive(Debug)]
+pub struct CreateVirtualNetwork<'a> {
+    pub account_identifier: &'a str,
+    pub params: CreateVirtualNetworkParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug)]
+pub struct CreateVirtualNetworkParams<'a> {
+    /// Must be unique within the account.
+    pub name: &'a str,
+    pub comment: Option<&'a str>,
+    /// Makes it the default virtual network of the account, instead of the current one.
+    pub is_default: Option<bool>,
+}
+
+impl EndpointSpec for CreateVirtualNetwork<'_> {
+    type JsonResponse = VirtualNetwork;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/virtual_networks",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// List virtual networks
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/virtual_networks/methods/list/>
+#[derive(Debug)]
+pub struct ListVirtualNetworks<'a> {
+    pub account_identifier: &'a str,
+    pub params: ListVirtualNetworksParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ListVirtualNetworksParams<'a> {
+    pub id: Option<Uuid>,
+    pub name: Option<&'a str>,
+    pub is_default: Option<bool>,
+    pub is_deleted: Option<bool>,
+}
+
+impl EndpointSpec for ListVirtualNetworks<'_> {
+    type JsonResponse = Vec<VirtualNetwork>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/virtual_networks",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+/// Get a virtual network
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/virtual_networks/methods/get/>
+#[derive(Debug)]
+pub struct VirtualNetworkDetails<'a> {
+    pub account_identifier: &'a str,
+    pub virtual_network_id: &'a str,
+}
+
+impl EndpointSpec for VirtualNetworkDetails<'_> {
+    type JsonResponse = VirtualNetwork;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/virtual_networks/{}",
+            self.account_identifier, self.virtual_network_id
+        )
+    }
+}
+
+/// Update a virtual network
+/// Only the given fields are changed.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/virtual_networks/methods/edit/>
+#[derive(Debug)]
+pub struct UpdateVirtualNetwork<'a> {
+    pub account_identifier: &'a str,
+    pub virtual_network_id: &'a str,
+    pub params: UpdateVirtualNetworkParams<'a>,
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct UpdateVirtualNetworkParams<'a> {
+    pub name: Option<&'a str>,
+    pub comment: Option<&'a str>,
+    pub is_default_network: Option<bool>,
+}
+
+impl EndpointSpec for UpdateVirtualNetwork<'_> {
+    type JsonResponse = VirtualNetwork;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/virtual_networks/{}",
+            self.account_identifier, self.virtual_network_id
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+/// Delete a virtual network
+/// The default virtual network, and virtual networks with routes, can't be deleted.
+/// <https://developers.cloudflare.com/api/resources/zero_trust/subresources/networks/subresources/virtual_networks/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteVirtualNetwork<'a> {
+    pub account_identifier: &'a str,
+    pub virtual_network_id: &'a str,
+}
+
+impl EndpointSpec for DeleteVirtualNetwork<'_> {
+    type JsonResponse = VirtualNetwork;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/teamnet/virtual_networks/{}",
+            self.account_identifier, self.virtual_network_id
+        )
+    }
+}
@@ -3,9 +3,10 @@ use super::WorkersSecret;
 use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
 
 use crate::framework::response::ApiSuccess;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Create Secret
+/// Adds a secret to a script, or replaces the secret if one with the same name already exists.
 /// <https://api.cloudflare.com/#worker-create-secret>
 #[derive(Debug)]
 pub struct CreateSecret<'a> {
@@ -41,9 +42,63 @@ impl EndpointSpec for CreateSecret<'_> {
 pub struct CreateSecretParams {
     /// the variable name of the secret that will be bound to the script
     pub name: String,
-    /// the string value of the secret
-    pub text: String,
-    // type of binding (e.g.secret_text)
-    #[serde(rename = "type")]
-    pub secret_type: String,
+    /// the type and value of the secret
+    #[serde(flatten)]
+    pub value: SecretValue,
+}
+
+/// The value of a secret, tagged with its binding type.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SecretValue {
+    /// A plain string secret
+    SecretText {
+        /// the string value of the secret
+        text: String,
+    },
+    /// A key usable through the WebCrypto API
+    SecretKey {
+        /// Algorithm-specific key parameters, as passed to `crypto.subtle.importKey`.
+        /// e.g. `{"name": "HMAC", "hash": "SHA-256"}`
+        algorithm: serde_json::Value,
+        /// Data format of the key
+        format: SecretKeyFormat,
+        /// Allowed operations with the key
+        usages: Vec<SecretKeyUsage>,
+        /// Base64-encoded key data. Required if `format` is "raw", "pkcs8", or "spki".
+        key_base64: Option<String>,
+        /// Key data in JSON Web Key format. Required if `format` is "jwk".
+        key_jwk: Option<serde_json::Value>,
+    },
+}
+
+impl SecretValue {
+    pub fn text(text: impl Into<String>) -> Self {
+        SecretValue::SecretText { text: text.into() }
+    }
+}
+
+/// Data format of a `secret_key` secret
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum SecretKeyFormat {
+    Raw,
+    Pkcs8,
+    Spki,
+    Jwk,
+}
+
+/// An operation allowed with a `secret_key` secret
+#[derive(Deserialize, Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum SecretKeyUsage {
+    Encrypt,
+    Decrypt,
+    Sign,
+    Verify,
+    DeriveKey,
+    DeriveBits,
+    WrapKey,
+    UnwrapKey,
 }
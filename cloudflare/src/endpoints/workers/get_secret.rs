@@ -0,0 +1,32 @@
+use super::WorkersSecret;
+
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Get Secret
+/// Returns the binding of a single secret. The secret value itself is never returned.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/subresources/secrets/methods/get/>
+#[derive(Debug)]
+pub struct GetSecret<'a> {
+    /// account id of owner of the script
+    pub account_identifier: &'a str,
+    /// the name of the script the secret is attached to
+    pub script_name: &'a str,
+    /// the variable name of the secret
+    pub secret_name: &'a str,
+}
+
+impl EndpointSpec for GetSecret<'_> {
+    type JsonResponse = WorkersSecret;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}/secrets/{}",
+            self.account_identifier, self.script_name, self.secret_name
+        )
+    }
+}
@@ -11,25 +11,35 @@ mod delete_route;
 mod delete_script;
 mod delete_secret;
 mod delete_tail;
+mod get_secret;
 mod list_bindings;
 mod list_routes;
 mod list_secrets;
 mod list_tails;
 mod send_tail_heartbeat;
+mod sync_secrets;
+mod upload_script;
 
 pub use create_route::{CreateRoute, CreateRouteParams};
-pub use create_secret::{CreateSecret, CreateSecretParams};
+pub use create_secret::{
+    CreateSecret, CreateSecretParams, SecretKeyFormat, SecretKeyUsage, SecretValue,
+};
 pub use create_tail::{CreateTail, CreateTailParams};
 pub use delete_do::DeleteDurableObject;
 pub use delete_route::DeleteRoute;
 pub use delete_script::DeleteScript;
 pub use delete_secret::DeleteSecret;
 pub use delete_tail::DeleteTail;
+pub use get_secret::GetSecret;
 pub use list_bindings::ListBindings;
 pub use list_routes::ListRoutes;
 pub use list_secrets::ListSecrets;
 pub use list_tails::ListTails;
 pub use send_tail_heartbeat::SendTailHeartbeat;
+pub use sync_secrets::{sync_secrets, SecretSyncAction, SecretSyncResult, SecretsSyncPlan};
+pub use upload_script::{
+    ScriptModule, ScriptModuleType, ScriptUpload, ScriptUploadMetadata, UploadScript,
+};
 
 /// Workers KV Route
 /// Routes are basic patterns used to enable or disable workers that match requests.
@@ -61,16 +71,47 @@ impl ApiResult for WorkersRouteIdOnly {}
 
 /// Secrets attach to a single script to be readable in only the script
 /// <https://api.cloudflare.com/#worker-secrets-properties>
+#[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct WorkersSecret {
     pub name: String,
+    /// type of binding (e.g. secret_text or secret_key)
     #[serde(rename = "type")]
     pub secret_type: String,
+    /// Algorithm-specific key parameters, for `secret_key` secrets.
+    pub algorithm: Option<serde_json::Value>,
+    /// Data format of the key, for `secret_key` secrets.
+    pub format: Option<SecretKeyFormat>,
+    /// Allowed operations with the key, for `secret_key` secrets.
+    pub usages: Option<Vec<SecretKeyUsage>>,
 }
 
 impl ApiResult for WorkersSecret {}
 impl ApiResult for Vec<WorkersSecret> {} // to parse arrays too
 
+/// A Worker script, as returned after an upload
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/methods/update/>
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct WorkersScript {
+    /// The name of the script.
+    pub id: String,
+    /// Hashed script content, can be used in a If-None-Match header when updating.
+    pub etag: Option<String>,
+    /// The names of handlers exported as part of the default export (e.g. "fetch").
+    #[serde(default)]
+    pub handlers: Vec<String>,
+    pub created_on: Option<DateTime<Utc>>,
+    pub modified_on: Option<DateTime<Utc>>,
+    pub compatibility_date: Option<String>,
+    #[serde(default)]
+    pub compatibility_flags: Vec<String>,
+    /// Whether Logpush is turned on for the Worker.
+    pub logpush: Option<bool>,
+    pub usage_model: Option<String>,
+}
+
+impl ApiResult for WorkersScript {}
+
 /// A Tail is attached to a single Worker and is impermanent
 /// <https://api.cloudflare.com/#worker-tail-properties>
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
@@ -132,6 +173,12 @@ pub enum WorkersBinding {
         name: String,
         bucket_name: String,
     },
+    SecretKey {
+        name: String,
+        algorithm: serde_json::Value,
+        format: SecretKeyFormat,
+        usages: Vec<SecretKeyUsage>,
+    },
     SecretText {
         name: String,
         // When fetching bindings, the text field of a Secret is not returned
@@ -0,0 +1,202 @@
+use super::{
+    CreateSecret, CreateSecretParams, DeleteSecret, ListSecrets, SecretValue, WorkersSecret,
+};
+
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+use std::collections::BTreeMap;
+
+/// The changes needed to make the secrets of a script match a desired set of secrets.
+///
+/// Secret values can't be read back from the API, so every desired secret is (re-)written.
+/// Secrets present on the script but absent from the desired set are removed.
+#[derive(Clone, Debug, Default)]
+pub struct SecretsSyncPlan {
+    /// Secrets to create or overwrite, in name order.
+    pub put: Vec<CreateSecretParams>,
+    /// Names of the secrets to remove, in name order.
+    pub delete: Vec<String>,
+}
+
+impl SecretsSyncPlan {
+    /// Computes the plan from the secrets currently attached to the script (as returned by
+    /// `ListSecrets`) and the desired secrets, keyed by name.
+    pub fn new(existing: &[WorkersSecret], desired: &BTreeMap<String, SecretValue>) -> Self {
+        let put = desired
+            .iter()
+            .map(|(name, value)| CreateSecretParams {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect();
+
+        let mut delete: Vec<String> = existing
+            .iter()
+            .filter(|secret| !desired.contains_key(&secret.name))
+            .map(|secret| secret.name.clone())
+            .collect();
+        delete.sort();
+
+        SecretsSyncPlan { put, delete }
+    }
+}
+
+/// What was done to a single secret during a sync.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SecretSyncAction {
+    /// The secret was created or overwritten.
+    Put,
+    /// The secret was removed from the script.
+    Delete,
+}
+
+/// The outcome of syncing a single secret.
+#[derive(Debug)]
+pub struct SecretSyncResult {
+    /// The variable name of the secret
+    pub name: String,
+    pub action: SecretSyncAction,
+    pub result: Result<(), ApiFailure>,
+}
+
+/// Makes the secrets of a script match `desired`.
+///
+/// All puts are applied before any removal, so a secret being renamed is never missing from the
+/// script. A failure on one secret doesn't stop the others; check each `SecretSyncResult`.
+/// Only a failure to list the current secrets is returned as an error.
+pub async fn sync_secrets(
+    client: &Client,
+    account_identifier: &str,
+    script_name: &str,
+    desired: &BTreeMap<String, SecretValue>,
+) -> Result<Vec<SecretSyncResult>, ApiFailure> {
+    let existing = client
+        .request(&ListSecrets {
+            account_identifier,
+            script_name,
+        })
+        .await?
+        .result;
+    let plan = SecretsSyncPlan::new(&existing, desired);
+
+    let mut results = Vec::with_capacity(plan.put.len() + plan.delete.len());
+    for params in plan.put {
+        let name = params.name.clone();
+        let result = client
+            .request(&CreateSecret {
+                account_identifier,
+                script_name,
+                params,
+            })
+            .await
+            .map(|_| ());
+        results.push(SecretSyncResult {
+            name,
+            action: SecretSyncAction::Put,
+            result,
+        });
+    }
+    for name in plan.delete {
+        let result = client
+            .request(&DeleteSecret {
+                account_identifier,
+                script_name,
+                secret_name: &name,
+            })
+            .await
+            .map(|_| ());
+        results.push(SecretSyncResult {
+            name,
+            action: SecretSyncAction::Delete,
+            result,
+        });
+    }
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn secret(name: &str) -> WorkersSecret {
+        WorkersSecret {
+            name: name.to_string(),
+            secret_type: "secret_text".to_string(),
+            algorithm: None,
+            format: None,
+            usages: None,
+        }
+    }
+
+    #[test]
+    fn test_plan_puts_desired_and_deletes_extra() {
+        let existing = vec![secret("KEEP"), secret("STALE")];
+        let desired = BTreeMap::from([
+            ("KEEP".to_string(), SecretValue::text("a")),
+            ("NEW".to_string(), SecretValue::text("b")),
+        ]);
+
+        let plan = SecretsSyncPlan::new(&existing, &desired);
+
+        let put: Vec<&str> = plan.put.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(put, vec!["KEEP", "NEW"]);
+        assert_eq!(plan.delete, vec!["STALE".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_secrets_reports_each_secret() {
+        let ok = json!({"result": {"name": "NEW", "type": "secret_text"}, "success": true});
+        let nothing = json!({"result": null, "success": true});
+        let list = json!({
+            "result": [{"name": "STALE", "type": "secret_text"}],
+            "success": true
+        });
+        let path = "/accounts/acc/workers/scripts/script/secrets";
+
+        let mut server = Server::new_async().await;
+        let list_mock = server
+            .mock("GET", path)
+            .with_body(list.to_string())
+            .create();
+        let put_mock = server
+            .mock("PUT", path)
+            .match_body(Matcher::Json(
+                json!({"name": "NEW", "type": "secret_text", "text": "value"}),
+            ))
+            .with_body(ok.to_string())
+            .create();
+        let delete_mock = server
+            .mock("DELETE", format!("{path}/STALE").as_str())
+            .with_body(nothing.to_string())
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let desired = BTreeMap::from([("NEW".to_string(), SecretValue::text("value"))]);
+        let results = sync_secrets(&client, "acc", "script", &desired)
+            .await
+            .unwrap();
+
+        list_mock.assert();
+        put_mock.assert();
+        delete_mock.assert();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].name, "NEW");
+        assert_eq!(results[0].action, SecretSyncAction::Put);
+        assert!(results[0].result.is_ok());
+        assert_eq!(results[1].name, "STALE");
+        assert_eq!(results[1].action, SecretSyncAction::Delete);
+        assert!(results[1].result.is_ok());
+    }
+}
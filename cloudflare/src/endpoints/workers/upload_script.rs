@@ -0,0 +1,137 @@
+use super::{WorkersBinding, WorkersScript};
+
+use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody};
+use crate::framework::response::ApiSuccess;
+use serde::Serialize;
+
+/// Upload Worker Module
+/// Uploads a Worker script (and its modules) using the multipart upload format.
+/// <https://developers.cloudflare.com/api/resources/workers/subresources/scripts/methods/update/>
+#[derive(Debug)]
+pub struct UploadScript<'a> {
+    /// Account ID of script owner
+    pub account_identifier: &'a str,
+    /// The name of the script to create or replace
+    pub script_name: &'a str,
+    /// The metadata and modules making up the script
+    pub params: ScriptUpload,
+}
+
+impl EndpointSpec for UploadScript<'_> {
+    type JsonResponse = WorkersScript;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/scripts/{}",
+            self.account_identifier, self.script_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        Some(RequestBody::MultiPart(&self.params))
+    }
+}
+
+/// A Worker script upload: a `metadata` part followed by one part per module.
+/// <https://developers.cloudflare.com/workers/configuration/multipart-upload-metadata/>
+#[derive(Clone, Debug)]
+pub struct ScriptUpload {
+    pub metadata: ScriptUploadMetadata,
+    pub modules: Vec<ScriptModule>,
+}
+
+impl MultipartBody for ScriptUpload {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        let mut parts = vec![(
+            "metadata".to_string(),
+            MultipartPart::Text(
+                serde_json::to_string(&self.metadata).expect("Failed to serialize metadata"),
+            ),
+        )];
+        for module in &self.modules {
+            parts.push((
+                module.name.clone(),
+                MultipartPart::File {
+                    filename: module.name.clone(),
+                    content_type: module.module_type.content_type().to_string(),
+                    bytes: module.content.clone(),
+                },
+            ));
+        }
+        parts
+    }
+}
+
+/// The `metadata` part of a Worker script upload.
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ScriptUploadMetadata {
+    /// Name of the part containing the main module, for ES module Workers.
+    pub main_module: Option<String>,
+    /// Name of the part containing the script, for service worker syntax Workers.
+    pub body_part: Option<String>,
+    /// Bindings to attach to the Worker.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bindings: Vec<WorkersBinding>,
+    /// Date indicating targeted support in the Workers runtime, e.g. "2024-01-01".
+    pub compatibility_date: Option<String>,
+    /// Flags that enable or disable certain features in the Workers runtime.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub compatibility_flags: Vec<String>,
+    /// Names of bindings of the currently deployed script to keep, e.g. `["secret_text"]`.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keep_bindings: Vec<String>,
+    /// Whether Logpush is turned on for the Worker.
+    pub logpush: Option<bool>,
+    /// Tags to attach to the script.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Usage model to apply to invocations, e.g. "standard".
+    pub usage_model: Option<String>,
+}
+
+/// A single module of a Worker script upload.
+#[derive(Clone, Debug)]
+pub struct ScriptModule {
+    /// The part name, which is also the module's file name, e.g. "worker.js".
+    pub name: String,
+    pub module_type: ScriptModuleType,
+    pub content: Vec<u8>,
+}
+
+/// The kind of a Worker module, which determines the MIME type of its part.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScriptModuleType {
+    /// An ES module (`application/javascript+module`)
+    EsModule,
+    /// A service worker syntax script or CommonJS module (`application/javascript`)
+    CommonJs,
+    /// A text blob (`text/plain`)
+    Text,
+    /// A WebAssembly module (`application/wasm`)
+    Wasm,
+    /// An arbitrary data blob (`application/octet-stream`)
+    Data,
+    /// A Python module (`text/x-python`)
+    Python,
+    /// A source map (`application/source-map`)
+    SourceMap,
+}
+
+impl ScriptModuleType {
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            ScriptModuleType::EsModule => "application/javascript+module",
+            ScriptModuleType::CommonJs => "application/javascript",
+            ScriptModuleType::Text => "text/plain",
+            ScriptModuleType::Wasm => "application/wasm",
+            ScriptModuleType::Data => "application/octet-stream",
+            ScriptModuleType::Python => "text/x-python",
+            ScriptModuleType::SourceMap => "application/source-map",
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::DispatchNamespace;
+
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+use serde::Serialize;
+
+/// Create dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/create/>
+#[derive(Debug)]
+pub struct CreateDispatchNamespace<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    pub params: CreateDispatchNamespaceParams,
+}
+
+impl EndpointSpec for CreateDispatchNamespace<'_> {
+    type JsonResponse = DispatchNamespace;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces",
+            self.account_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct CreateDispatchNamespaceParams {
+    /// The name of the dispatch namespace
+    pub name: Option<String>,
+}
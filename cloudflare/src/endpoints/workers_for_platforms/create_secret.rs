@@ -0,0 +1,37 @@
+use crate::endpoints::workers::{CreateSecretParams, WorkersSecret};
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Add or update a secret of a Worker script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/secrets/methods/update/>
+#[derive(Debug)]
+pub struct CreateSecret<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script to attach the secret to
+    pub script_name: &'a str,
+    /// The contents of the secret
+    pub params: CreateSecretParams,
+}
+
+impl EndpointSpec for CreateSecret<'_> {
+    type JsonResponse = WorkersSecret;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/secrets",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.params).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
@@ -0,0 +1,27 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Delete dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteDispatchNamespace<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace to delete
+    pub namespace_name: &'a str,
+}
+
+impl EndpointSpec for DeleteDispatchNamespace<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}",
+            self.account_identifier, self.namespace_name
+        )
+    }
+}
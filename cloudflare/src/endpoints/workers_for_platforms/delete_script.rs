@@ -0,0 +1,44 @@
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+use serde::Serialize;
+
+/// Delete a Worker script from a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteScript<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script to remove
+    pub script_name: &'a str,
+    pub params: DeleteScriptParams,
+}
+
+impl EndpointSpec for DeleteScript<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct DeleteScriptParams {
+    /// If set to true, delete will not be stopped by associated service binding, durable object,
+    /// or other binding. Any of these associated bindings/durable objects will be deleted along
+    /// with the script.
+    pub force: Option<bool>,
+}
@@ -0,0 +1,31 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Remove a secret from a Worker script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/secrets/methods/delete/>
+#[derive(Debug)]
+pub struct DeleteSecret<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script to remove the secret from
+    pub script_name: &'a str,
+    /// The variable name of the secret
+    pub secret_name: &'a str,
+}
+
+impl EndpointSpec for DeleteSecret<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::DELETE
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/secrets/{}",
+            self.account_identifier, self.namespace_name, self.script_name, self.secret_name
+        )
+    }
+}
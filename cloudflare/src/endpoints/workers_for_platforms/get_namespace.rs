@@ -0,0 +1,29 @@
+use super::DispatchNamespace;
+
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Get dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/get/>
+#[derive(Debug)]
+pub struct GetDispatchNamespace<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+}
+
+impl EndpointSpec for GetDispatchNamespace<'_> {
+    type JsonResponse = DispatchNamespace;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}",
+            self.account_identifier, self.namespace_name
+        )
+    }
+}
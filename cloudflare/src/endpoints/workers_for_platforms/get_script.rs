@@ -0,0 +1,31 @@
+use super::DispatchNamespaceScript;
+
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Get a Worker script from a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/get/>
+#[derive(Debug)]
+pub struct GetScript<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for GetScript<'_> {
+    type JsonResponse = DispatchNamespaceScript;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+}
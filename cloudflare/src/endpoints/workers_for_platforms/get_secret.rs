@@ -0,0 +1,33 @@
+use crate::endpoints::workers::WorkersSecret;
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// Get a secret binding of a Worker script in a dispatch namespace
+/// The secret value itself is never returned.
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/secrets/methods/get/>
+#[derive(Debug)]
+pub struct GetSecret<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script
+    pub script_name: &'a str,
+    /// The variable name of the secret
+    pub secret_name: &'a str,
+}
+
+impl EndpointSpec for GetSecret<'_> {
+    type JsonResponse = WorkersSecret;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/secrets/{}",
+            self.account_identifier, self.namespace_name, self.script_name, self.secret_name
+        )
+    }
+}
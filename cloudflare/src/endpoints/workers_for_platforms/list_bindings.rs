@@ -0,0 +1,31 @@
+use crate::endpoints::workers::WorkersBinding;
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// List Bindings
+/// Lists all bindings of a Worker script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/bindings/methods/get/>
+#[derive(Debug)]
+pub struct ListBindings<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script to list bindings for
+    pub script_name: &'a str,
+}
+
+impl EndpointSpec for ListBindings<'_> {
+    type JsonResponse = Vec<WorkersBinding>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/bindings",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+}
@@ -0,0 +1,27 @@
+use super::DispatchNamespace;
+
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::ApiSuccess;
+
+/// List dispatch namespaces
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/list/>
+#[derive(Debug)]
+pub struct ListDispatchNamespaces<'a> {
+    /// Account ID of the namespaces owner
+    pub account_identifier: &'a str,
+}
+
+impl EndpointSpec for ListDispatchNamespaces<'_> {
+    type JsonResponse = Vec<DispatchNamespace>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces",
+            self.account_identifier
+        )
+    }
+}
@@ -0,0 +1,31 @@
+use crate::framework::endpoint::{EndpointSpec, Method};
+use crate::framework::response::{ApiResult, ApiSuccess};
+
+/// List the tags of a Worker script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/tags/methods/list/>
+#[derive(Debug)]
+pub struct ListScriptTags<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script
+    pub script_name: &'a str,
+}
+
+impl ApiResult for Vec<String> {}
+
+impl EndpointSpec for ListScriptTags<'_> {
+    type JsonResponse = Vec<String>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/tags",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+}
@@ -0,0 +1,204 @@
+use crate::endpoints::workers::{WorkersBinding, WorkersScript};
+use crate::framework::response::ApiResult;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+mod create_namespace;
+mod create_secret;
+mod delete_namespace;
+mod delete_script;
+mod delete_script_tag;
+mod delete_secret;
+mod get_namespace;
+mod get_script;
+mod get_script_settings;
+mod get_secret;
+mod list_bindings;
+mod list_namespaces;
+mod list_script_tags;
+mod list_secrets;
+mod patch_script_settings;
+mod put_script_tag;
+mod put_script_tags;
+mod upload_script;
+
+pub use create_namespace::{CreateDispatchNamespace, CreateDispatchNamespaceParams};
+pub use create_secret::CreateSecret;
+pub use delete_namespace::DeleteDispatchNamespace;
+pub use delete_script::{DeleteScript, DeleteScriptParams};
+pub use delete_script_tag::DeleteScriptTag;
+pub use delete_secret::DeleteSecret;
+pub use get_namespace::GetDispatchNamespace;
+pub use get_script::GetScript;
+pub use get_script_settings::GetScriptSettings;
+pub use get_secret::GetSecret;
+pub use list_bindings::ListBindings;
+pub use list_namespaces::ListDispatchNamespaces;
+pub use list_script_tags::ListScriptTags;
+pub use list_secrets::ListSecrets;
+pub use patch_script_settings::PatchScriptSettings;
+pub use put_script_tag::PutScriptTag;
+pub use put_script_tags::PutScriptTags;
+pub use upload_script::UploadScript;
+
+/// A dispatch namespace holds the user Workers of a Workers for Platforms customer.
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/methods/get/>
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DispatchNamespace {
+    /// API Resource UUID tag.
+    pub namespace_id: String,
+    /// Name of the Workers for Platforms dispatch namespace.
+    pub namespace_name: String,
+    pub created_on: Option<DateTime<Utc>>,
+    /// Identifier of the user who created the namespace.
+    pub created_by: Option<String>,
+    pub modified_on: Option<DateTime<Utc>>,
+    /// Identifier of the user who last modified the namespace.
+    pub modified_by: Option<String>,
+    /// The current number of scripts in this dispatch namespace.
+    pub script_count: Option<u64>,
+    /// Whether the Workers in the namespace are executed in a "trusted" manner.
+    pub trusted_workers: Option<bool>,
+}
+
+impl ApiResult for DispatchNamespace {}
+impl ApiResult for Vec<DispatchNamespace> {}
+
+/// A user Worker script uploaded into a dispatch namespace.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct DispatchNamespaceScript {
+    /// Name of the dispatch namespace the script belongs to.
+    pub dispatch_namespace: String,
+    pub created_on: Option<DateTime<Utc>>,
+    pub modified_on: Option<DateTime<Utc>>,
+    pub script: WorkersScript,
+}
+
+impl ApiResult for DispatchNamespaceScript {}
+
+/// Settings of a user Worker script in a dispatch namespace.
+/// Fields left unset are not modified when patching settings.
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/settings/methods/edit/>
+#[serde_with::skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct ScriptSettings {
+    /// Bindings attached to the Worker.
+    pub bindings: Option<Vec<WorkersBinding>>,
+    /// Date indicating targeted support in the Workers runtime, e.g. "2024-01-01".
+    pub compatibility_date: Option<String>,
+    /// Flags that enable or disable certain features in the Workers runtime.
+    pub compatibility_flags: Option<Vec<String>>,
+    /// Whether Logpush is turned on for the Worker.
+    pub logpush: Option<bool>,
+    /// Tags associated with the Worker.
+    pub tags: Option<Vec<String>>,
+    /// Usage model for the Worker invocations, e.g. "standard".
+    pub usage_model: Option<String>,
+}
+
+impl ApiResult for ScriptSettings {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn namespace_json() -> serde_json::Value {
+        json!({
+            "namespace_id": "0f2ac74b498b48028cb68387c421e279",
+            "namespace_name": "customers",
+            "created_on": "2024-01-01T00:00:00Z",
+            "script_count": 3,
+            "trusted_workers": false
+        })
+    }
+
+    fn success(result: serde_json::Value) -> String {
+        json!({"result": result, "success": true, "errors": [], "messages": []}).to_string()
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_namespace_crud() {
+        let collection = "/accounts/acc/workers/dispatch/namespaces";
+        let item = "/accounts/acc/workers/dispatch/namespaces/customers";
+
+        let mut server = Server::new_async().await;
+        let create_mock = server
+            .mock("POST", collection)
+            .match_body(Matcher::Json(json!({"name": "customers"})))
+            .with_body(success(namespace_json()))
+            .create();
+        let get_mock = server
+            .mock("GET", item)
+            .with_body(success(namespace_json()))
+            .create();
+        let list_mock = server
+            .mock("GET", collection)
+            .with_body(success(json!([namespace_json()])))
+            .create();
+        let delete_mock = server
+            .mock("DELETE", item)
+            .with_body(success(json!(null)))
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+
+        let created = client
+            .request(&CreateDispatchNamespace {
+                account_identifier: "acc",
+                params: CreateDispatchNamespaceParams {
+                    name: Some("customers".to_string()),
+                },
+            })
+            .await
+            .unwrap()
+            .result;
+        assert_eq!(created.namespace_name, "customers");
+        assert_eq!(created.script_count, Some(3));
+
+        let fetched = client
+            .request(&GetDispatchNamespace {
+                account_identifier: "acc",
+                namespace_name: "customers",
+            })
+            .await
+            .unwrap()
+            .result;
+        assert_eq!(fetched, created);
+
+        let listed = client
+            .request(&ListDispatchNamespaces {
+                account_identifier: "acc",
+            })
+            .await
+            .unwrap()
+            .result;
+        assert_eq!(listed, vec![created]);
+
+        client
+            .request(&DeleteDispatchNamespace {
+                account_identifier: "acc",
+                namespace_name: "customers",
+            })
+            .await
+            .unwrap();
+
+        create_mock.assert();
+        get_mock.assert();
+        list_mock.assert();
+        delete_mock.assert();
+    }
+}
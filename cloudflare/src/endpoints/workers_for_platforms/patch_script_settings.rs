@@ -0,0 +1,46 @@
+use super::ScriptSettings;
+
+use crate::framework::endpoint::{EndpointSpec, Method, MultipartBody, MultipartPart, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Patch the settings of a Worker script in a dispatch namespace
+/// Only the fields set in `params` are modified.
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/settings/methods/edit/>
+#[derive(Debug)]
+pub struct PatchScriptSettings<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script
+    pub script_name: &'a str,
+    pub params: ScriptSettings,
+}
+
+impl EndpointSpec for PatchScriptSettings<'_> {
+    type JsonResponse = ScriptSettings;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PATCH
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/settings",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        Some(RequestBody::MultiPart(&self.params))
+    }
+}
+
+impl MultipartBody for ScriptSettings {
+    fn parts(&self) -> Vec<(String, MultipartPart)> {
+        vec![(
+            "settings".to_string(),
+            MultipartPart::Text(serde_json::to_string(self).expect("Failed to serialize settings")),
+        )]
+    }
+}
@@ -0,0 +1,36 @@
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Replace all the tags of a Worker script in a dispatch namespace
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/subresources/tags/methods/update/>
+#[derive(Debug)]
+pub struct PutScriptTags<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script
+    pub script_name: &'a str,
+    /// The new set of tags
+    pub tags: Vec<String>,
+}
+
+impl EndpointSpec for PutScriptTags<'_> {
+    type JsonResponse = Vec<String>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}/tags",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let body = serde_json::to_string(&self.tags).unwrap();
+        Some(RequestBody::Json(body))
+    }
+}
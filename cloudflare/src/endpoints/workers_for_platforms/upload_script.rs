@@ -0,0 +1,125 @@
+use crate::endpoints::workers::{ScriptUpload, WorkersScript};
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::ApiSuccess;
+
+/// Upload Worker Module into a dispatch namespace
+/// Uses the same multipart format as [`crate::endpoints::workers::UploadScript`].
+/// <https://developers.cloudflare.com/api/resources/workers_for_platforms/subresources/dispatch/subresources/namespaces/subresources/scripts/methods/update/>
+#[derive(Debug)]
+pub struct UploadScript<'a> {
+    /// Account ID of the namespace owner
+    pub account_identifier: &'a str,
+    /// The name of the dispatch namespace
+    pub namespace_name: &'a str,
+    /// The name of the script to create or replace
+    pub script_name: &'a str,
+    /// The metadata and modules making up the script
+    pub params: ScriptUpload,
+}
+
+impl EndpointSpec for UploadScript<'_> {
+    type JsonResponse = WorkersScript;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/workers/dispatch/namespaces/{}/scripts/{}",
+            self.account_identifier, self.namespace_name, self.script_name
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        Some(RequestBody::MultiPart(&self.params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::endpoints::workers::{ScriptModule, ScriptModuleType, ScriptUploadMetadata};
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_upload_script_sends_metadata_and_modules() {
+        let response = json!({
+            "result": {"id": "customer-worker", "handlers": ["fetch"]},
+            "success": true,
+            "errors": [],
+            "messages": []
+        });
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock(
+                "PUT",
+                "/accounts/acc/workers/dispatch/namespaces/ns/scripts/customer-worker",
+            )
+            .match_header(
+                "content-type",
+                Matcher::Regex("multipart/form-data; boundary=.*".into()),
+            )
+            .match_request(|req| {
+                let body = req.body().unwrap().to_vec();
+                let body = String::from_utf8_lossy(&body);
+
+                body.contains(r#"name="metadata""#)
+                    && body.contains(
+                        r#"{"main_module":"worker.js","compatibility_date":"2024-01-01","tags":["customer"]}"#,
+                    )
+                    && body.contains(r#"name="worker.js"; filename="worker.js""#)
+                    && body.contains("Content-Type: application/javascript+module")
+                    && body.contains("export default {}")
+                    && body.contains(r#"name="data.bin"; filename="data.bin""#)
+                    && body.contains("Content-Type: application/octet-stream")
+            })
+            .with_body(response.to_string())
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let endpoint = UploadScript {
+            account_identifier: "acc",
+            namespace_name: "ns",
+            script_name: "customer-worker",
+            params: ScriptUpload {
+                metadata: ScriptUploadMetadata {
+                    main_module: Some("worker.js".to_string()),
+                    compatibility_date: Some("2024-01-01".to_string()),
+                    tags: vec!["customer".to_string()],
+                    ..Default::default()
+                },
+                modules: vec![
+                    ScriptModule {
+                        name: "worker.js".to_string(),
+                        module_type: ScriptModuleType::EsModule,
+                        content: b"export default {}".to_vec(),
+                    },
+                    ScriptModule {
+                        name: "data.bin".to_string(),
+                        module_type: ScriptModuleType::Data,
+                        content: vec![1, 2, 3],
+                    },
+                ],
+            },
+        };
+        let script = client.request(&endpoint).await.unwrap().result;
+
+        mock.assert();
+        assert_eq!(script.id, "customer-worker");
+        assert_eq!(script.handlers, vec!["fetch".to_string()]);
+    }
+}
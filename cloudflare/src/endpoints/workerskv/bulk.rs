@@ -0,0 +1,389 @@
+use super::delete_bulk::DeleteBulk;
+use super::write_bulk::{KeyValuePair, WriteBulk};
+use super::WorkersKvBulkResult;
+
+use crate::framework::client::async_api::Client;
+use crate::framework::response::{ApiFailure, ApiResponse, ApiSuccess};
+use futures_util::stream::{self, StreamExt};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::future::Future;
+use std::time::Duration;
+
+/// The maximum number of keys in a single bulk request.
+pub const MAX_BULK_KEYS: usize = 10_000;
+
+/// The maximum size, in bytes, of a single bulk request body.
+pub const MAX_BULK_REQUEST_BYTES: usize = 100_000_000;
+
+/// How the bulk helpers split and send their requests.
+#[derive(Clone, Debug)]
+pub struct BulkOptions {
+    /// The maximum number of keys per request. Capped at [`MAX_BULK_KEYS`].
+    pub max_keys: usize,
+    /// The maximum request body size in bytes. Capped at [`MAX_BULK_REQUEST_BYTES`].
+    pub max_bytes: usize,
+    /// The number of requests in flight at once.
+    pub concurrency: usize,
+    /// How many times keys reported in `unsuccessful_keys` are sent again.
+    pub max_retries: u32,
+    /// How long to wait before the first retry. The wait doubles with every further retry.
+    pub retry_backoff: Duration,
+}
+
+impl Default for BulkOptions {
+    fn default() -> Self {
+        BulkOptions {
+            max_keys: MAX_BULK_KEYS,
+            max_bytes: MAX_BULK_REQUEST_BYTES,
+            concurrency: 4,
+            max_retries: 3,
+            retry_backoff: Duration::from_millis(500),
+        }
+    }
+}
+
+/// A bulk request that failed as a whole.
+#[derive(Debug)]
+pub struct BulkChunkError {
+    /// The keys that were part of the failed request.
+    pub keys: Vec<String>,
+    pub error: ApiFailure,
+}
+
+/// The aggregate outcome of a bulk write or delete.
+#[derive(Debug, Default)]
+pub struct BulkReport {
+    /// The number of requests sent, retries included.
+    pub requests: usize,
+    /// Number of keys successfully updated.
+    pub successful_key_count: u64,
+    /// Keys still reported as unsuccessful once the retries were exhausted.
+    pub unsuccessful_keys: Vec<String>,
+    /// Requests that failed entirely. None of their keys were updated.
+    pub errors: Vec<BulkChunkError>,
+}
+
+impl BulkReport {
+    /// Whether every key was updated.
+    pub fn is_complete(&self) -> bool {
+        self.unsuccessful_keys.is_empty() && self.errors.is_empty()
+    }
+
+    fn merge(&mut self, other: BulkReport) {
+        self.requests += other.requests;
+        self.successful_key_count += other.successful_key_count;
+        self.unsuccessful_keys.extend(other.unsuccessful_keys);
+        self.errors.extend(other.errors);
+    }
+}
+
+/// Splits `items` into chunks holding at most `max_keys` items whose JSON array encoding is at
+/// most `max_bytes` long. An item too large to fit on its own gets a chunk of its own, which the
+/// API will reject.
+pub fn chunk<T: Serialize>(items: Vec<T>, max_keys: usize, max_bytes: usize) -> Vec<Vec<T>> {
+    let max_keys = max_keys.clamp(1, MAX_BULK_KEYS);
+    let max_bytes = max_bytes.min(MAX_BULK_REQUEST_BYTES);
+
+    let mut chunks = vec![];
+    let mut current = vec![];
+    // The enclosing `[]`.
+    let mut current_bytes = 2;
+    for item in items {
+        // The item and its separating comma.
+        let size = serde_json::to_vec(&item).map_or(0, |v| v.len()) + 1;
+        if !current.is_empty() && (current.len() == max_keys || current_bytes + size > max_bytes) {
+            chunks.push(std::mem::take(&mut current));
+            current_bytes = 2;
+        }
+        current.push(item);
+        current_bytes += size;
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+    chunks
+}
+
+/// Writes any number of key-value pairs, split into as many [`WriteBulk`] requests as needed.
+pub async fn write_bulk(
+    client: &Client,
+    account_identifier: &str,
+    namespace_identifier: &str,
+    pairs: Vec<KeyValuePair>,
+    options: &BulkOptions,
+) -> BulkReport {
+    run(
+        pairs,
+        options,
+        |pair| &pair.key,
+        move |bulk_key_value_pairs| async move {
+            client
+                .request(&WriteBulk {
+                    account_identifier,
+                    namespace_identifier,
+                    bulk_key_value_pairs,
+                })
+                .await
+        },
+    )
+    .await
+}
+
+/// Removes any number of keys, split into as many [`DeleteBulk`] requests as needed.
+pub async fn delete_bulk(
+    client: &Client,
+    account_identifier: &str,
+    namespace_identifier: &str,
+    keys: Vec<String>,
+    options: &BulkOptions,
+) -> BulkReport {
+    run(
+        keys,
+        options,
+        |key| key,
+        move |bulk_keys| async move {
+            client
+                .request(&DeleteBulk {
+                    account_identifier,
+                    namespace_identifier,
+                    bulk_keys,
+                })
+                .await
+        },
+    )
+    .await
+}
+
+async fn run<T, K, S, F>(items: Vec<T>, options: &BulkOptions, key: K, send: S) -> BulkReport
+where
+    T: Serialize + Clone,
+    K: Fn(&T) -> &String + Copy,
+    S: Fn(Vec<T>) -> F + Copy,
+    F: Future<Output = ApiResponse<ApiSuccess<WorkersKvBulkResult>>>,
+{
+    let chunks = chunk(items, options.max_keys, options.max_bytes);
+    stream::iter(chunks)
+        .map(|chunk| send_with_retries(chunk, options, key, send))
+        .buffer_unordered(options.concurrency.max(1))
+        .fold(
+            BulkReport::default(),
+            |mut report, chunk_report| async move {
+                report.merge(chunk_report);
+                report
+            },
+        )
+        .await
+}
+
+async fn send_with_retries<T, K, S, F>(
+    mut chunk: Vec<T>,
+    options: &BulkOptions,
+    key: K,
+    send: S,
+) -> BulkReport
+where
+    T: Clone,
+    K: Fn(&T) -> &String,
+    S: Fn(Vec<T>) -> F,
+    F: Future<Output = ApiResponse<ApiSuccess<WorkersKvBulkResult>>>,
+{
+    let mut report = BulkReport::default();
+    let mut attempt = 0;
+    loop {
+        report.requests += 1;
+        let result = match send(chunk.clone()).await {
+            Ok(response) => response.result,
+            Err(error) => {
+                report.errors.push(BulkChunkError {
+                    keys: chunk.iter().map(|item| key(item).clone()).collect(),
+                    error,
+                });
+                return report;
+            }
+        };
+        report.successful_key_count += result.successful_key_count.unwrap_or_default();
+
+        let unsuccessful: HashSet<String> = result
+            .unsuccessful_keys
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+        if unsuccessful.is_empty() {
+            return report;
+        }
+        // Only keys of this chunk are kept, whatever else the API reported.
+        chunk.retain(|item| unsuccessful.contains(key(item)));
+        if attempt == options.max_retries || chunk.is_empty() {
+            report.unsuccessful_keys = chunk.iter().map(|item| key(item).clone()).collect();
+            report.unsuccessful_keys.sort();
+            return report;
+        }
+        sleep(retry_delay(options.retry_backoff, attempt)).await;
+        attempt += 1;
+    }
+}
+
+/// The wait before retry number `attempt + 1`: `base` doubled `attempt` times.
+fn retry_delay(base: Duration, attempt: u32) -> Duration {
+    base.saturating_mul(1 << attempt.min(16))
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+// There is no timer to wait on in wasm, so retries are sent right away.
+#[cfg(target_arch = "wasm32")]
+async fn sleep(_duration: Duration) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    #[test]
+    fn test_chunk_respects_count_and_size() {
+        let keys: Vec<String> = (0..25).map(|i| format!("{i:02}")).collect();
+
+        let by_count = chunk(keys.clone(), 10, MAX_BULK_REQUEST_BYTES);
+        assert_eq!(
+            by_count.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![10, 10, 5]
+        );
+
+        // Each key encodes to `"00",`, 5 bytes, so 4 of them and the brackets fit in 22 bytes.
+        let by_size = chunk(keys.clone(), MAX_BULK_KEYS, 22);
+        let expected: Vec<Vec<String>> = keys.chunks(4).map(<[String]>::to_vec).collect();
+        assert_eq!(by_size, expected);
+        assert_eq!(by_size.last().unwrap(), &vec!["24".to_string()]);
+    }
+
+    #[test]
+    fn test_retry_delay_doubles() {
+        let base = Duration::from_millis(100);
+        assert_eq!(retry_delay(base, 0), Duration::from_millis(100));
+        assert_eq!(retry_delay(base, 1), Duration::from_millis(200));
+        assert_eq!(retry_delay(base, 3), Duration::from_millis(800));
+    }
+
+    #[tokio::test]
+    async fn test_write_bulk_retries_unsuccessful_keys() {
+        let path = "/accounts/acc/storage/kv/namespaces/ns/bulk";
+        let pair = |key: &str| KeyValuePair {
+            key: key.to_string(),
+            value: "v".to_string(),
+            expiration: None,
+            expiration_ttl: None,
+            metadata: None,
+            base64: None,
+        };
+
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("PUT", path)
+            .match_body(Matcher::PartialJson(json!([{"key": "a"}, {"key": "b"}])))
+            .with_body(
+                json!({
+                    "result": {"successful_key_count": 1, "unsuccessful_keys": ["b"]},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+        let retry = server
+            .mock("PUT", path)
+            .match_body(Matcher::PartialJson(json!([{"key": "b"}])))
+            .with_body(
+                json!({
+                    "result": {"successful_key_count": 1, "unsuccessful_keys": []},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let options = BulkOptions {
+            retry_backoff: Duration::from_millis(50),
+            ..Default::default()
+        };
+        let started = std::time::Instant::now();
+        let report = write_bulk(&client, "acc", "ns", vec![pair("a"), pair("b")], &options).await;
+
+        first.assert();
+        retry.assert();
+        assert!(started.elapsed() >= options.retry_backoff);
+        assert_eq!(report.requests, 2);
+        assert_eq!(report.successful_key_count, 2);
+        assert!(report.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_delete_bulk_reports_only_keys_of_the_failed_chunk() {
+        let path = "/accounts/acc/storage/kv/namespaces/ns/bulk";
+        let result = |count: u64, unsuccessful: &[&str]| {
+            json!({
+                "result": {"successful_key_count": count, "unsuccessful_keys": unsuccessful},
+                "success": true
+            })
+            .to_string()
+        };
+
+        let mut server = Server::new_async().await;
+        let complete = server
+            .mock("DELETE", path)
+            .match_body(Matcher::Json(json!(["a", "b"])))
+            .with_body(result(2, &[]))
+            .create();
+        // The API reports a key of another chunk too, which must not end up in the report.
+        let failed = server
+            .mock("DELETE", path)
+            .match_body(Matcher::Json(json!(["c", "d"])))
+            .with_body(result(1, &["d", "a"]))
+            .create();
+        let retried = server
+            .mock("DELETE", path)
+            .match_body(Matcher::Json(json!(["d"])))
+            .with_body(result(0, &["d"]))
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let options = BulkOptions {
+            max_keys: 2,
+            max_retries: 1,
+            retry_backoff: Duration::ZERO,
+            ..Default::default()
+        };
+        let keys = ["a", "b", "c", "d"].map(String::from).to_vec();
+        let report = delete_bulk(&client, "acc", "ns", keys, &options).await;
+
+        complete.assert();
+        failed.assert();
+        retried.assert();
+        assert_eq!(report.requests, 3);
+        assert_eq!(report.successful_key_count, 3);
+        assert_eq!(report.unsuccessful_keys, vec!["d".to_string()]);
+        assert!(report.errors.is_empty());
+        assert!(!report.is_complete());
+    }
+}
@@ -5,6 +5,7 @@ use crate::framework::response::ApiSuccess;
 /// Remove multiple KV pairs from the namespace.
 ///
 /// Body should be an array of up to 10,000 keys to be removed.
+/// Use [`super::bulk::delete_bulk`] to remove more keys than fit in a single request.
 /// A `404` is returned if a delete action is for a namespace ID the account doesn't have.
 ///
 /// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/methods/bulk_delete/>
@@ -1,12 +1,17 @@
-use super::Key;
+use super::{Key, Typed};
 
 use crate::framework::endpoint::{serialize_query, EndpointSpec, Method};
 
 use crate::framework::response::ApiSuccess;
+use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::fmt::Debug;
 
 /// Lists a namespace's keys.
 ///
+/// Use [`ListNamespaceKeys::typed`] to deserialize the metadata of each key into a type of your
+/// own.
+///
 /// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/keys/methods/list/>
 #[derive(Debug)]
 pub struct ListNamespaceKeys<'a> {
@@ -15,6 +20,13 @@ pub struct ListNamespaceKeys<'a> {
     pub params: ListNamespaceKeysParams,
 }
 
+impl<'a> ListNamespaceKeys<'a> {
+    /// Deserializes the metadata of each key into `M` instead of a [`serde_json::Value`].
+    pub fn typed<M>(self) -> Typed<Self, M> {
+        Typed::new(self)
+    }
+}
+
 impl EndpointSpec for ListNamespaceKeys<'_> {
     type JsonResponse = Vec<Key>;
     type ResponseType = ApiSuccess<Self::JsonResponse>;
@@ -34,6 +46,22 @@ impl EndpointSpec for ListNamespaceKeys<'_> {
     }
 }
 
+impl<M: DeserializeOwned + Debug> EndpointSpec for Typed<ListNamespaceKeys<'_>, M> {
+    type JsonResponse = Vec<Key<M>>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        self.endpoint.query()
+    }
+}
+
 #[serde_with::skip_serializing_none]
 #[derive(Serialize, Clone, Debug, Default)]
 pub struct ListNamespaceKeysParams {
@@ -41,3 +69,69 @@ pub struct ListNamespaceKeysParams {
     pub cursor: Option<String>,
     pub prefix: Option<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Owner {
+        owner: String,
+    }
+
+    #[tokio::test]
+    async fn test_list_keys_with_typed_metadata() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/accounts/acc/storage/kv/namespaces/ns/keys")
+            .match_query(Matcher::Any)
+            .with_body(
+                json!({
+                    "result": [
+                        {"name": "a", "metadata": {"owner": "me"}},
+                        {"name": "b"}
+                    ],
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let keys = client
+            .request(
+                &ListNamespaceKeys {
+                    account_identifier: "acc",
+                    namespace_identifier: "ns",
+                    params: Default::default(),
+                }
+                .typed::<Owner>(),
+            )
+            .await
+            .unwrap()
+            .result;
+
+        mock.assert();
+        assert_eq!(
+            keys[0].metadata,
+            Some(Owner {
+                owner: "me".to_string()
+            })
+        );
+        assert_eq!(keys[1].metadata, None);
+    }
+}
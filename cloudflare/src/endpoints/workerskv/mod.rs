@@ -1,20 +1,29 @@
 use crate::framework::response::ApiResult;
 use chrono::DateTime;
 use chrono::{TimeZone, Utc};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt::Debug;
+use std::marker::PhantomData;
 
+pub mod bulk;
 pub mod create_namespace;
 pub mod delete_bulk;
 pub mod delete_key;
 pub mod get_namespace;
 pub mod list_namespace_keys;
 pub mod list_namespaces;
+pub mod read_bulk;
 pub mod read_key;
 pub mod read_key_metadata;
+pub mod read_key_stream;
+pub mod read_key_with_metadata;
 pub mod remove_namespace;
 pub mod rename_namespace;
+pub mod transfer;
 pub mod write_bulk;
 pub mod write_key;
+pub mod write_key_stream;
 
 /// Workers KV Namespace
 ///
@@ -36,10 +45,33 @@ impl ApiResult for WorkersKvNamespace {}
 
 impl ApiResult for Vec<WorkersKvNamespace> {}
 
+/// An endpoint deserializing the values or metadata it returns into `T` instead of
+/// [`serde_json::Value`]s.
+///
+/// Built with the `typed` method of the endpoints supporting it, e.g.
+/// `ReadKeyMetadata { .. }.typed::<MyMetadata>()`.
+#[derive(Debug)]
+pub struct Typed<E, T = serde_json::Value> {
+    endpoint: E,
+    target: PhantomData<fn() -> T>,
+}
+
+impl<E, T> Typed<E, T> {
+    fn new(endpoint: E) -> Self {
+        Typed {
+            endpoint,
+            target: PhantomData,
+        }
+    }
+}
+
 /// A name for a value. A value stored under a given key may be retrieved via the same key.
+///
+/// `M` is the type the key's metadata is deserialized into. It defaults to
+/// [`serde_json::Value`], which accepts any metadata.
 #[serde_with::skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
-pub struct Key {
+pub struct Key<M = serde_json::Value> {
     /// A key's name. The name may be at most 512 bytes.
     /// All printable, non-whitespace characters are valid.
     /// Use percent-encoding to define key names as part of a URL.
@@ -52,8 +84,7 @@ pub struct Key {
     pub expiration: Option<DateTime<Utc>>,
 
     /// Arbitrary JSON that is associated with a key.
-    #[serde(default)]
-    pub metadata: Option<serde_json::Value>,
+    pub metadata: Option<M>,
 }
 
 pub fn deserialize_option_timestamp<'de, D>(
@@ -70,8 +101,8 @@ where
     Ok(None)
 }
 
-impl ApiResult for Key {}
-impl ApiResult for Vec<Key> {}
+impl<M: DeserializeOwned + Debug> ApiResult for Key<M> {}
+impl<M: DeserializeOwned + Debug> ApiResult for Vec<Key<M>> {}
 
 fn url_encode_key(key: &str) -> String {
     urlencoding::encode(key).to_string()
@@ -81,7 +112,7 @@ fn url_encode_key(key: &str) -> String {
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 pub struct WorkersKvBulkResult {
     /// Number of keys successfully updated.
-    pub successful_key_count: Option<i8>,
+    pub successful_key_count: Option<u64>,
 
     /// Name of the keys that failed to be fully updated. They should be retried.
     // TODO: Ambiguity with the official docs; it does not seem to be optional. It's an empty array if no keys failed.
@@ -0,0 +1,352 @@
+use super::Typed;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiResult, ApiSuccess};
+use chrono::{DateTime, Utc};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+
+/// Retrieve up to 100 KV pairs from the namespace in a single request.
+///
+/// Keys that don't exist are returned as [`BulkEntry::Missing`], so they can be told apart from
+/// keys storing a JSON `null` with the `json` type. Use [`ReadBulk::typed`] to deserialize the
+/// values into a type of your own.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/methods/bulk_get/>
+#[derive(Debug)]
+pub struct ReadBulk<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub params: ReadBulkParams,
+}
+
+impl<'a> ReadBulk<'a> {
+    /// Deserializes the values into `V` instead of a [`serde_json::Value`].
+    pub fn typed<V>(self) -> Typed<Self, V> {
+        Typed::new(self)
+    }
+}
+
+impl EndpointSpec for ReadBulk<'_> {
+    type JsonResponse = ReadBulkResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/bulk/get",
+            self.account_identifier, self.namespace_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        // Without metadata, a missing key and a stored `null` are both returned as `null`.
+        Some(RequestBody::Json(self.params.to_body(true)))
+    }
+}
+
+impl<V: DeserializeOwned + Debug> EndpointSpec for Typed<ReadBulk<'_>, V> {
+    type JsonResponse = ReadBulkResult<V>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        self.endpoint.body()
+    }
+}
+
+/// Retrieve up to 100 KV pairs from the namespace in a single request, along with their metadata
+/// and expiration.
+///
+/// Use [`ReadBulkWithMetadata::typed`] to deserialize the values and metadata into types of your
+/// own.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/methods/bulk_get/>
+#[derive(Debug)]
+pub struct ReadBulkWithMetadata<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub params: ReadBulkParams,
+}
+
+impl<'a> ReadBulkWithMetadata<'a> {
+    /// Deserializes the values into `V` and the metadata into `M`, instead of
+    /// [`serde_json::Value`]s.
+    pub fn typed<V, M>(self) -> Typed<Self, (V, M)> {
+        Typed::new(self)
+    }
+}
+
+impl EndpointSpec for ReadBulkWithMetadata<'_> {
+    type JsonResponse = ReadBulkWithMetadataResult;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/bulk/get",
+            self.account_identifier, self.namespace_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        Some(RequestBody::Json(self.params.to_body(true)))
+    }
+}
+
+impl<V, M> EndpointSpec for Typed<ReadBulkWithMetadata<'_>, (V, M)>
+where
+    V: DeserializeOwned + Debug,
+    M: DeserializeOwned + Debug,
+{
+    type JsonResponse = ReadBulkWithMetadataResult<V, M>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        self.endpoint.body()
+    }
+}
+
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ReadBulkParams {
+    /// Array of keys to retrieve, at most 100.
+    pub keys: Vec<String>,
+    /// Whether to parse the values as JSON or return them as strings. Defaults to `text`.
+    #[serde(rename = "type")]
+    pub value_type: Option<ReadBulkValueType>,
+}
+
+impl ReadBulkParams {
+    pub(super) fn to_body(&self, with_metadata: bool) -> String {
+        if self.keys.len() > 100 {
+            panic!("Bulk read request can only contain up to 100 keys.");
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Body<'a> {
+            #[serde(flatten)]
+            params: &'a ReadBulkParams,
+            with_metadata: bool,
+        }
+
+        serde_json::to_string(&Body {
+            params: self,
+            with_metadata,
+        })
+        .unwrap()
+    }
+}
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadBulkValueType {
+    /// Values are returned as JSON strings.
+    Text,
+    /// Values are parsed as JSON. Keys whose value isn't valid JSON fail the whole request.
+    Json,
+}
+
+/// A key requested by a bulk read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BulkEntry<T> {
+    /// The key doesn't exist.
+    Missing,
+    /// The key exists.
+    Value(T),
+}
+
+impl<T> BulkEntry<T> {
+    /// Returns the value, or `None` if the key doesn't exist.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            BulkEntry::Missing => None,
+            BulkEntry::Value(value) => Some(value),
+        }
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for BulkEntry<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            None => BulkEntry::Missing,
+            Some(value) => BulkEntry::Value(value),
+        })
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(from = "ReadBulkWithMetadataResult<V, IgnoredAny>")]
+#[serde(bound = "V: DeserializeOwned")]
+pub struct ReadBulkResult<V = serde_json::Value> {
+    /// The requested keys, mapped to their value.
+    pub values: BTreeMap<String, BulkEntry<V>>,
+}
+
+impl<V> From<ReadBulkWithMetadataResult<V, IgnoredAny>> for ReadBulkResult<V> {
+    fn from(result: ReadBulkWithMetadataResult<V, IgnoredAny>) -> Self {
+        let values = result.values.into_iter().map(|(key, entry)| {
+            let entry = match entry {
+                BulkEntry::Missing => BulkEntry::Missing,
+                BulkEntry::Value(value) => BulkEntry::Value(value.value),
+            };
+            (key, entry)
+        });
+        ReadBulkResult {
+            values: values.collect(),
+        }
+    }
+}
+
+impl<V: DeserializeOwned + Debug> ApiResult for ReadBulkResult<V> {}
+
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(bound = "V: DeserializeOwned, M: DeserializeOwned")]
+pub struct ReadBulkWithMetadataResult<V = serde_json::Value, M = serde_json::Value> {
+    /// The requested keys, mapped to their value and metadata.
+    pub values: BTreeMap<String, BulkEntry<BulkValue<V, M>>>,
+}
+
+impl<V: DeserializeOwned + Debug, M: DeserializeOwned + Debug> ApiResult
+    for ReadBulkWithMetadataResult<V, M>
+{
+}
+
+/// A value returned by [`ReadBulkWithMetadata`].
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(bound = "V: DeserializeOwned, M: DeserializeOwned")]
+pub struct BulkValue<V = serde_json::Value, M = serde_json::Value> {
+    /// The value, as a JSON string for the `text` type.
+    pub value: V,
+    /// Arbitrary JSON that is associated with the key. `None` for keys written without metadata.
+    #[serde(default)]
+    pub metadata: Option<M>,
+    /// The time at which the key will expire. `None` for keys that will not expire.
+    #[serde(default)]
+    #[serde(deserialize_with = "super::deserialize_option_timestamp")]
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Mock, Server, ServerGuard};
+    use serde_json::json;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Owner {
+        owner: String,
+    }
+
+    fn mock_bulk_get(server: &mut ServerGuard) -> Mock {
+        server
+            .mock("POST", "/accounts/acc/storage/kv/namespaces/ns/bulk/get")
+            .match_body(Matcher::Json(json!({
+                "keys": ["present", "null", "missing"],
+                "type": "json",
+                "withMetadata": true
+            })))
+            .with_body(
+                json!({
+                    "result": {"values": {
+                        "present": {"value": {"a": 1}, "metadata": {"owner": "me"}},
+                        "null": {"value": null, "metadata": null},
+                        "missing": null
+                    }},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .expect(2)
+            .create()
+    }
+
+    fn params() -> ReadBulkParams {
+        ReadBulkParams {
+            keys: vec!["present".into(), "null".into(), "missing".into()],
+            value_type: Some(ReadBulkValueType::Json),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_read_bulk_tells_missing_from_null() {
+        let mut server = Server::new_async().await;
+        let mock = mock_bulk_get(&mut server);
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let values = client
+            .request(&ReadBulk {
+                account_identifier: "acc",
+                namespace_identifier: "ns",
+                params: params(),
+            })
+            .await
+            .unwrap()
+            .result
+            .values;
+        assert_eq!(values["present"], BulkEntry::Value(json!({"a": 1})));
+        assert_eq!(values["null"], BulkEntry::Value(json!(null)));
+        assert_eq!(values["missing"], BulkEntry::Missing);
+
+        let typed = client
+            .request(
+                &ReadBulkWithMetadata {
+                    account_identifier: "acc",
+                    namespace_identifier: "ns",
+                    params: params(),
+                }
+                .typed::<Option<BTreeMap<String, u32>>, Owner>(),
+            )
+            .await
+            .unwrap()
+            .result
+            .values;
+
+        mock.assert();
+        let BulkEntry::Value(present) = &typed["present"] else {
+            panic!("expected a value for \"present\"");
+        };
+        assert_eq!(present.value, Some(BTreeMap::from([("a".to_string(), 1)])));
+        assert_eq!(
+            present.metadata,
+            Some(Owner {
+                owner: "me".to_string()
+            })
+        );
+        let BulkEntry::Value(null) = &typed["null"] else {
+            panic!("expected a value for \"null\"");
+        };
+        assert_eq!((&null.value, &null.metadata), (&None, &None));
+        assert_eq!(typed["missing"], BulkEntry::Missing);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::framework::endpoint::EndpointSpec;
 use crate::framework::endpoint::Method;
-use crate::framework::response::ApiResult;
+use crate::framework::response::{ApiResult, ApiSuccess, ResponseConverter};
+use chrono::{DateTime, TimeZone, Utc};
 
 /// Returns the value associated with the given key in the given namespace.
 ///
@@ -36,3 +37,67 @@ impl EndpointSpec for ReadKey<'_> {
         )
     }
 }
+
+/// Returns the value associated with the given key in the given namespace, along with its
+/// expiration time (parsed from the `expiration` response header).
+///
+/// Use URL-encoding to use special characters (for example, `:`, `!`, `%`) in the key name.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/values/methods/get/>
+#[derive(Debug)]
+pub struct ReadKeyWithExpiration<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub key: &'a str,
+}
+
+impl EndpointSpec for ReadKeyWithExpiration<'_> {
+    const IS_RAW_BODY: bool = true;
+
+    type JsonResponse = ();
+    type ResponseType = KeyValue;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/values/{}",
+            self.account_identifier,
+            self.namespace_identifier,
+            super::url_encode_key(self.key)
+        )
+    }
+}
+
+/// A value read from a namespace, along with its expiration time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValue {
+    /// The value associated with the key.
+    pub value: Vec<u8>,
+    /// The time at which the key will expire. `None` for keys that will not expire.
+    pub expiration: Option<DateTime<Utc>>,
+}
+
+impl ResponseConverter<()> for KeyValue {
+    fn from_raw(bytes: Vec<u8>) -> Self {
+        KeyValue {
+            value: bytes,
+            expiration: None,
+        }
+    }
+    fn from_raw_with_headers(bytes: Vec<u8>, headers: &http::HeaderMap) -> Self {
+        let expiration = headers
+            .get("expiration")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<i64>().ok())
+            .and_then(|timestamp| Utc.timestamp_opt(timestamp, 0).single());
+        KeyValue {
+            value: bytes,
+            expiration,
+        }
+    }
+    fn from_json(_api: ApiSuccess<()>) -> Self {
+        panic!("This endpoint does not return JSON")
+    }
+}
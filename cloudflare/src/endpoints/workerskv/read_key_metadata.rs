@@ -1,11 +1,17 @@
+use super::Typed;
 use crate::framework::endpoint::EndpointSpec;
 use crate::framework::endpoint::Method;
 use crate::framework::response::{ApiResult, ApiSuccess};
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
 
 /// Returns the metadata associated with the given key in the given namespace.
 ///
 /// Use URL-encoding to use special characters (for example, `:`, `!`, `%`) in the key name.
 ///
+/// The result is `None` for keys written without metadata. Use [`ReadKeyMetadata::typed`] to
+/// deserialize the metadata into a type of your own.
+///
 /// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/metadata/methods/get/>
 #[derive(Debug)]
 pub struct ReadKeyMetadata<'a> {
@@ -14,7 +20,14 @@ pub struct ReadKeyMetadata<'a> {
     pub key: &'a str,
 }
 
-impl ApiResult for Option<serde_json::Value> {}
+impl<'a> ReadKeyMetadata<'a> {
+    /// Deserializes the metadata into `M` instead of a [`serde_json::Value`].
+    pub fn typed<M>(self) -> Typed<Self, M> {
+        Typed::new(self)
+    }
+}
+
+impl<M: DeserializeOwned + Debug> ApiResult for Option<M> {}
 
 impl EndpointSpec for ReadKeyMetadata<'_> {
     type JsonResponse = Option<serde_json::Value>;
@@ -32,3 +45,15 @@ impl EndpointSpec for ReadKeyMetadata<'_> {
         )
     }
 }
+
+impl<M: DeserializeOwned + Debug> EndpointSpec for Typed<ReadKeyMetadata<'_>, M> {
+    type JsonResponse = Option<M>;
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+}
@@ -0,0 +1,37 @@
+use crate::framework::endpoint::EndpointSpec;
+use crate::framework::endpoint::Method;
+use crate::framework::response::ByteStream;
+
+/// Returns the value associated with the given key in the given namespace, as a stream of bytes.
+///
+/// This is the streaming variant of [`ReadKey`](super::read_key::ReadKey): the value is not
+/// buffered in memory, which matters for large values (up to 25 MiB) read concurrently.
+///
+/// Use URL-encoding to use special characters (for example, `:`, `!`, `%`) in the key name.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/values/methods/get/>
+#[derive(Debug)]
+pub struct ReadKeyStream<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub key: &'a str,
+}
+
+impl EndpointSpec for ReadKeyStream<'_> {
+    const IS_STREAMING_BODY: bool = true;
+
+    type JsonResponse = ();
+    type ResponseType = ByteStream;
+
+    fn method(&self) -> Method {
+        Method::GET
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/values/{}",
+            self.account_identifier,
+            self.namespace_identifier,
+            super::url_encode_key(self.key)
+        )
+    }
+}
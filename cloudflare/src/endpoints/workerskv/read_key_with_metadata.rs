@@ -0,0 +1,317 @@
+use super::read_bulk::{BulkEntry, ReadBulkParams, ReadBulkWithMetadataResult};
+use super::read_key::ReadKeyWithExpiration;
+use super::read_key_metadata::ReadKeyMetadata;
+use super::Typed;
+use crate::framework::client::async_api::Client;
+use crate::framework::endpoint::{EndpointSpec, Method, RequestBody};
+use crate::framework::response::{ApiFailure, ApiResponse, ApiSuccess, ResponseConverter};
+use chrono::{DateTime, Utc};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use std::fmt::Debug;
+
+/// Returns the text value associated with the given key in the given namespace, along with its
+/// expiration time and metadata, in a single request.
+///
+/// The value and metadata are read together with the bulk read endpoint, so they are consistent
+/// with each other. That endpoint only returns values as text: use [`read_key_with_metadata`]
+/// for binary values.
+///
+/// The result is `None` if the key doesn't exist. Use [`ReadTextKeyWithMetadata::typed`] to
+/// deserialize the metadata into a type of your own.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/methods/bulk_get/>
+#[derive(Debug)]
+pub struct ReadTextKeyWithMetadata<'a> {
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+    pub key: &'a str,
+}
+
+impl<'a> ReadTextKeyWithMetadata<'a> {
+    /// Deserializes the metadata into `M` instead of a [`serde_json::Value`].
+    pub fn typed<M>(self) -> Typed<Self, M> {
+        Typed::new(self)
+    }
+}
+
+impl EndpointSpec for ReadTextKeyWithMetadata<'_> {
+    type JsonResponse = ReadBulkWithMetadataResult<String>;
+    type ResponseType = Option<KeyValueWithMetadata<serde_json::Value, String>>;
+
+    fn method(&self) -> Method {
+        Method::POST
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/bulk/get",
+            self.account_identifier, self.namespace_identifier
+        )
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        let params = ReadBulkParams {
+            keys: vec![self.key.to_string()],
+            value_type: None,
+        };
+        Some(RequestBody::Json(params.to_body(true)))
+    }
+}
+
+impl<M: DeserializeOwned + Debug> EndpointSpec for Typed<ReadTextKeyWithMetadata<'_>, M> {
+    type JsonResponse = ReadBulkWithMetadataResult<String, M>;
+    type ResponseType = Option<KeyValueWithMetadata<M, String>>;
+
+    fn method(&self) -> Method {
+        self.endpoint.method()
+    }
+    fn path(&self) -> String {
+        self.endpoint.path()
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        self.endpoint.body()
+    }
+}
+
+/// Returns the value associated with the given key in the given namespace, byte for byte, along
+/// with its expiration time and metadata.
+///
+/// The value is read with [`ReadKeyWithExpiration`] and the metadata with [`ReadKeyMetadata`]:
+/// two requests, so a write landing in between can pair a value with the metadata of another
+/// write. Use [`ReadTextKeyWithMetadata`] to read a text value and its metadata in one request.
+///
+/// The result is `None` if the key doesn't exist.
+pub async fn read_key_with_metadata<M: DeserializeOwned + Debug>(
+    client: &Client,
+    account_identifier: &str,
+    namespace_identifier: &str,
+    key: &str,
+) -> ApiResponse<Option<KeyValueWithMetadata<M>>> {
+    let value = client
+        .request(&ReadKeyWithExpiration {
+            account_identifier,
+            namespace_identifier,
+            key,
+        })
+        .await;
+    let value = match value {
+        Ok(value) => value,
+        Err(ApiFailure::Error(StatusCode::NOT_FOUND, _)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let metadata = client
+        .request(
+            &ReadKeyMetadata {
+                account_identifier,
+                namespace_identifier,
+                key,
+            }
+            .typed::<M>(),
+        )
+        .await;
+    let metadata = match metadata {
+        Ok(metadata) => metadata.result,
+        // The key was deleted in the meantime.
+        Err(ApiFailure::Error(StatusCode::NOT_FOUND, _)) => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    Ok(Some(KeyValueWithMetadata {
+        value: value.value,
+        expiration: value.expiration,
+        metadata,
+    }))
+}
+
+/// A value read from a namespace, along with its expiration time and metadata.
+///
+/// The value is the raw bytes `Vec<u8>`, or a `String` when read with
+/// [`ReadTextKeyWithMetadata`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyValueWithMetadata<M = serde_json::Value, V = Vec<u8>> {
+    /// The value associated with the key.
+    pub value: V,
+    /// The time at which the key will expire. `None` for keys that will not expire.
+    pub expiration: Option<DateTime<Utc>>,
+    /// The metadata associated with the key. `None` for keys written without metadata.
+    pub metadata: Option<M>,
+}
+
+impl<M> ResponseConverter<ReadBulkWithMetadataResult<String, M>>
+    for Option<KeyValueWithMetadata<M, String>>
+{
+    fn from_raw(_bytes: Vec<u8>) -> Self {
+        panic!("This endpoint does not return raw bytes")
+    }
+    fn from_json(api: ApiSuccess<ReadBulkWithMetadataResult<String, M>>) -> Self {
+        match api.result.values.into_values().next()? {
+            BulkEntry::Missing => None,
+            BulkEntry::Value(value) => Some(KeyValueWithMetadata {
+                value: value.value,
+                expiration: value.expiration,
+                metadata: value.metadata,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::async_api::Client;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use chrono::TimeZone;
+    use mockito::{Matcher, Mock, Server, ServerGuard};
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Owner {
+        owner: String,
+    }
+
+    fn mock_bulk_get(server: &mut ServerGuard, key: &str, value: serde_json::Value) -> Mock {
+        server
+            .mock("POST", "/accounts/acc/storage/kv/namespaces/ns/bulk/get")
+            .match_body(Matcher::Json(json!({"keys": [key], "withMetadata": true})))
+            .with_body(json!({"result": {"values": {key: value}}, "success": true}).to_string())
+            .create()
+    }
+
+    #[tokio::test]
+    async fn test_read_key_with_metadata() {
+        let mut server = Server::new_async().await;
+        let present = mock_bulk_get(
+            &mut server,
+            "my key",
+            json!({"value": "value", "metadata": {"owner": "me"}, "expiration": 1700000000}),
+        );
+        let missing = mock_bulk_get(&mut server, "missing", json!(null));
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let read = client
+            .request(
+                &ReadTextKeyWithMetadata {
+                    account_identifier: "acc",
+                    namespace_identifier: "ns",
+                    key: "my key",
+                }
+                .typed::<Owner>(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        let not_found = client
+            .request(&ReadTextKeyWithMetadata {
+                account_identifier: "acc",
+                namespace_identifier: "ns",
+                key: "missing",
+            })
+            .await
+            .unwrap();
+
+        present.assert();
+        missing.assert();
+        assert_eq!(read.value, "value");
+        assert_eq!(read.expiration, Utc.timestamp_opt(1700000000, 0).single());
+        assert_eq!(
+            read.metadata,
+            Some(Owner {
+                owner: "me".to_string()
+            })
+        );
+        assert_eq!(not_found, None);
+    }
+
+    #[cfg(feature = "blocking")]
+    #[test]
+    fn test_read_key_with_metadata_blocking() {
+        use crate::framework::client::blocking_api::HttpApiClient;
+
+        let mut server = Server::new();
+        let mock = mock_bulk_get(&mut server, "key", json!({"value": "value"}));
+
+        let client = HttpApiClient::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let read = client
+            .request(&ReadTextKeyWithMetadata {
+                account_identifier: "acc",
+                namespace_identifier: "ns",
+                key: "key",
+            })
+            .unwrap()
+            .unwrap();
+
+        mock.assert();
+        assert_eq!(read.value, "value");
+        assert_eq!((read.expiration, read.metadata), (None, None));
+    }
+
+    #[tokio::test]
+    async fn test_read_key_with_metadata_keeps_binary_values() {
+        let value = [0xff, 0x00, 0xfe, b'v'];
+        let mut server = Server::new_async().await;
+        let read_value = server
+            .mock("GET", "/accounts/acc/storage/kv/namespaces/ns/values/bin")
+            .with_header("content-type", "application/octet-stream")
+            .with_header("expiration", "1700000000")
+            .with_body(value)
+            .create();
+        let read_metadata = server
+            .mock("GET", "/accounts/acc/storage/kv/namespaces/ns/metadata/bin")
+            .with_body(json!({"result": {"owner": "me"}, "success": true}).to_string())
+            .create();
+        let missing = server
+            .mock("GET", "/accounts/acc/storage/kv/namespaces/ns/values/missing")
+            .with_status(404)
+            .with_body(
+                json!({"result": null, "success": false, "errors": [{"code": 10009, "message": "get: 'key not found'"}]})
+                    .to_string(),
+            )
+            .create();
+
+        let client = Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap();
+        let read = read_key_with_metadata::<Owner>(&client, "acc", "ns", "bin")
+            .await
+            .unwrap()
+            .unwrap();
+        let not_found = read_key_with_metadata::<Owner>(&client, "acc", "ns", "missing")
+            .await
+            .unwrap();
+
+        read_value.assert();
+        read_metadata.assert();
+        missing.assert();
+        assert_eq!(read.value, value);
+        assert_eq!(read.expiration, Utc.timestamp_opt(1700000000, 0).single());
+        assert_eq!(
+            read.metadata,
+            Some(Owner {
+                owner: "me".to_string()
+            })
+        );
+        assert_eq!(not_found, None);
+    }
+}
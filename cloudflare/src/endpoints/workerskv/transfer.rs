@@ -0,0 +1,789 @@
+use super::bulk::{self, BulkOptions, BulkReport, MAX_BULK_KEYS};
+use super::list_namespace_keys::{ListNamespaceKeys, ListNamespaceKeysParams};
+use super::read_key::ReadKey;
+use super::write_bulk::KeyValuePair;
+use super::Key;
+
+use crate::framework::client::async_api::Client;
+use crate::framework::response::ApiFailure;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use chrono::Utc;
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use serde_with::{
+    base64::{Base64, Standard},
+    formats::Padded,
+    serde_as,
+};
+use std::collections::VecDeque;
+use std::io;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+/// Keys expiring sooner than this many seconds from now are skipped when writing: the API only
+/// accepts expirations at least 60 seconds in the future.
+const MIN_EXPIRATION_SECONDS: i64 = 60;
+
+/// A namespace to transfer keys from or to. Each side has its own client, so a transfer can
+/// cross accounts.
+#[derive(Clone, Copy)]
+pub struct Namespace<'a> {
+    pub client: &'a Client,
+    pub account_identifier: &'a str,
+    pub namespace_identifier: &'a str,
+}
+
+/// One line of a namespace archive: a JSON object per key, with its value base64-encoded.
+#[serde_as]
+#[serde_with::skip_serializing_none]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveRecord {
+    pub key: String,
+    #[serde_as(as = "Base64<Standard, Padded>")]
+    pub value: Vec<u8>,
+    /// The time, measured in number of seconds since the UNIX epoch, at which the key expires.
+    pub expiration: Option<i64>,
+    /// Arbitrary JSON that is associated with the key.
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl ArchiveRecord {
+    fn into_pair(self) -> KeyValuePair {
+        KeyValuePair {
+            key: self.key,
+            value: STANDARD.encode(self.value),
+            expiration: self.expiration,
+            expiration_ttl: None,
+            metadata: self.metadata,
+            base64: Some(true),
+        }
+    }
+
+    fn expires_too_soon(&self, now: i64) -> bool {
+        self.expiration
+            .is_some_and(|expiration| expiration < now + MIN_EXPIRATION_SECONDS)
+    }
+}
+
+/// How a transfer lists, reads and writes keys.
+#[derive(Clone, Debug)]
+pub struct TransferOptions {
+    /// The number of keys listed per page, up to 1000. Defaults to the API's default of 1000.
+    pub page_size: Option<u16>,
+    /// The number of values read at once.
+    pub read_concurrency: usize,
+    /// How writes and deletes are batched.
+    pub bulk: BulkOptions,
+    /// The maximum size, in bytes, of the archive records a restore holds in memory before
+    /// writing them.
+    pub max_batch_bytes: usize,
+}
+
+impl Default for TransferOptions {
+    fn default() -> Self {
+        TransferOptions {
+            page_size: None,
+            read_concurrency: 16,
+            bulk: BulkOptions::default(),
+            max_batch_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// How far a transfer got. Pass it back to resume an interrupted dump or copy.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// The listing cursor of the next page to transfer. `None` before the first page, and once
+    /// the transfer is complete.
+    pub cursor: Option<String>,
+    /// Whether the transfer is complete.
+    pub done: bool,
+    /// The number of keys transferred so far.
+    pub keys: u64,
+    /// The number of keys skipped so far, because they were deleted while listing or (when
+    /// writing) expire in less than a minute.
+    pub skipped: u64,
+    /// The last key written to the target namespace. A restore resumes after it.
+    #[serde(default)]
+    pub last_key: Option<String>,
+}
+
+/// Errors that stop a transfer. The last checkpoint passed to `on_page` is still valid.
+#[derive(thiserror::Error, Debug)]
+pub enum TransferError {
+    #[error("API request failed: {0}")]
+    Api(#[from] ApiFailure),
+    #[error("I/O error on the archive: {0}")]
+    Io(#[from] io::Error),
+    #[error("invalid archive record on line {line}: {error}")]
+    Archive {
+        line: usize,
+        error: serde_json::Error,
+    },
+    /// The key to resume a restore after isn't in the archive.
+    #[error("the archive has no key {0:?} to resume after")]
+    ResumeKeyNotFound(String),
+    /// Some keys couldn't be written or deleted. The report lists them.
+    #[error("{} keys failed to transfer", .0.unsuccessful_keys.len() + .0.errors.iter().map(|e| e.keys.len()).sum::<usize>())]
+    Incomplete(BulkReport),
+}
+
+/// Writes every key of `source` to `writer` as a newline-delimited JSON archive of
+/// [`ArchiveRecord`]s.
+///
+/// `on_page` is called with a checkpoint once each page of keys is written. Passing the last one
+/// back as `start` resumes the dump; the archive should then be appended to.
+pub async fn dump<W: AsyncWrite + Unpin>(
+    source: Namespace<'_>,
+    writer: &mut W,
+    start: Checkpoint,
+    options: &TransferOptions,
+    mut on_page: impl FnMut(&Checkpoint),
+) -> Result<Checkpoint, TransferError> {
+    let mut checkpoint = start;
+    while !checkpoint.done {
+        let page = read_page(source, checkpoint.cursor.as_deref(), options).await?;
+        let mut lines = vec![];
+        for record in &page.records {
+            serde_json::to_writer(&mut lines, record).map_err(io::Error::from)?;
+            lines.push(b'\n');
+        }
+        writer.write_all(&lines).await?;
+        writer.flush().await?;
+        checkpoint.keys += page.records.len() as u64;
+        checkpoint.skipped += page.deleted;
+        checkpoint.advance(page.next);
+        on_page(&checkpoint);
+    }
+    Ok(checkpoint)
+}
+
+/// Writes every record of a newline-delimited JSON archive to `target`.
+///
+/// Records are written in batches of at most [`TransferOptions::max_batch_bytes`]. `on_batch` is
+/// called with a checkpoint once each batch is written; its `last_key` is the last record
+/// written. Passing that checkpoint back as `start` with the same archive resumes the restore
+/// after that record.
+pub async fn restore<R: AsyncBufRead + Unpin>(
+    target: Namespace<'_>,
+    reader: R,
+    start: Checkpoint,
+    options: &TransferOptions,
+    mut on_batch: impl FnMut(&Checkpoint),
+) -> Result<Checkpoint, TransferError> {
+    let max_keys = MAX_BULK_KEYS * options.bulk.concurrency.max(1);
+    let mut checkpoint = start;
+    let mut resume_after = checkpoint.last_key.clone();
+    let mut batch = vec![];
+    let mut batch_bytes = 0;
+    let mut lines = reader.lines();
+    let mut index = 0;
+    while let Some(line) = lines.next_line().await? {
+        index += 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: ArchiveRecord = serde_json::from_str(&line)
+            .map_err(|error| TransferError::Archive { line: index, error })?;
+        if let Some(key) = &resume_after {
+            if record.key == *key {
+                resume_after = None;
+            }
+            continue;
+        }
+        batch_bytes += line.len();
+        batch.push(record);
+        if batch.len() == max_keys || batch_bytes >= options.max_batch_bytes {
+            write_records(target, std::mem::take(&mut batch), options, &mut checkpoint).await?;
+            batch_bytes = 0;
+            on_batch(&checkpoint);
+        }
+    }
+    if let Some(key) = resume_after {
+        return Err(TransferError::ResumeKeyNotFound(key));
+    }
+    if !batch.is_empty() {
+        write_records(target, batch, options, &mut checkpoint).await?;
+        on_batch(&checkpoint);
+    }
+    checkpoint.done = true;
+    Ok(checkpoint)
+}
+
+/// Copies every key of `source` to `target`, overwriting keys that exist in both.
+///
+/// `on_page` is called with a checkpoint once each page of keys is written. Passing the last one
+/// back as `start` resumes the copy.
+pub async fn copy(
+    source: Namespace<'_>,
+    target: Namespace<'_>,
+    start: Checkpoint,
+    options: &TransferOptions,
+    mut on_page: impl FnMut(&Checkpoint),
+) -> Result<Checkpoint, TransferError> {
+    let mut checkpoint = start;
+    while !checkpoint.done {
+        let page = read_page(source, checkpoint.cursor.as_deref(), options).await?;
+        let mut written = checkpoint.clone();
+        write_records(target, page.records, options, &mut written).await?;
+        checkpoint = written;
+        checkpoint.skipped += page.deleted;
+        checkpoint.advance(page.next);
+        on_page(&checkpoint);
+    }
+    Ok(checkpoint)
+}
+
+/// Makes `target` an exact copy of `source`: copies every key, then removes the keys of `target`
+/// that aren't in `source`. Returns the final checkpoint and the number of removed keys.
+pub async fn mirror(
+    source: Namespace<'_>,
+    target: Namespace<'_>,
+    start: Checkpoint,
+    options: &TransferOptions,
+    on_page: impl FnMut(&Checkpoint),
+) -> Result<(Checkpoint, u64), TransferError> {
+    let checkpoint = copy(source, target, start, options, on_page).await?;
+    let removed = prune(source, target, options).await?;
+    Ok((checkpoint, removed))
+}
+
+/// Removes the keys of `target` that aren't in `source`, and returns how many were removed.
+///
+/// Both namespaces are listed page by page and compared as they go, relying on keys being listed
+/// in lexicographic order, so only a page of each and a batch of keys to delete are held in
+/// memory.
+pub async fn prune(
+    source: Namespace<'_>,
+    target: Namespace<'_>,
+    options: &TransferOptions,
+) -> Result<u64, TransferError> {
+    let batch_size = MAX_BULK_KEYS * options.bulk.concurrency.max(1);
+    let mut source_keys = KeyLister::new(source, options);
+    let mut target_keys = KeyLister::new(target, options);
+    let mut extra = vec![];
+    let mut removed = 0;
+    while let Some(name) = target_keys.next().await? {
+        let in_source = loop {
+            match source_keys.peek().await? {
+                Some(source_name) if *source_name < name => {
+                    source_keys.next().await?;
+                }
+                Some(source_name) => break *source_name == name,
+                None => break false,
+            }
+        };
+        if !in_source {
+            extra.push(name);
+            if extra.len() == batch_size {
+                removed += delete_keys(target, std::mem::take(&mut extra), options).await?;
+            }
+        }
+    }
+    if !extra.is_empty() {
+        removed += delete_keys(target, extra, options).await?;
+    }
+    Ok(removed)
+}
+
+async fn delete_keys(
+    target: Namespace<'_>,
+    keys: Vec<String>,
+    options: &TransferOptions,
+) -> Result<u64, TransferError> {
+    let report = bulk::delete_bulk(
+        target.client,
+        target.account_identifier,
+        target.namespace_identifier,
+        keys,
+        &options.bulk,
+    )
+    .await;
+    if !report.is_complete() {
+        return Err(TransferError::Incomplete(report));
+    }
+    Ok(report.successful_key_count)
+}
+
+impl Checkpoint {
+    fn advance(&mut self, next: Option<String>) {
+        self.done = next.is_none();
+        self.cursor = next;
+    }
+}
+
+async fn list_page(
+    namespace: Namespace<'_>,
+    cursor: Option<&str>,
+    options: &TransferOptions,
+) -> Result<(Vec<Key>, Option<String>), ApiFailure> {
+    let response = namespace
+        .client
+        .request(&ListNamespaceKeys {
+            account_identifier: namespace.account_identifier,
+            namespace_identifier: namespace.namespace_identifier,
+            params: ListNamespaceKeysParams {
+                limit: options.page_size,
+                cursor: cursor.map(str::to_string),
+                prefix: None,
+            },
+        })
+        .await?;
+    let next = response
+        .result_info
+        .as_ref()
+        .and_then(|info| info.get("cursor"))
+        .and_then(|cursor| cursor.as_str())
+        .filter(|cursor| !cursor.is_empty())
+        .map(str::to_string);
+    Ok((response.result, next))
+}
+
+/// The names of the keys of a namespace, listed a page at a time.
+struct KeyLister<'a> {
+    namespace: Namespace<'a>,
+    options: &'a TransferOptions,
+    page: VecDeque<String>,
+    cursor: Option<String>,
+    done: bool,
+}
+
+impl<'a> KeyLister<'a> {
+    fn new(namespace: Namespace<'a>, options: &'a TransferOptions) -> Self {
+        KeyLister {
+            namespace,
+            options,
+            page: VecDeque::new(),
+            cursor: None,
+            done: false,
+        }
+    }
+
+    async fn peek(&mut self) -> Result<Option<&String>, ApiFailure> {
+        while self.page.is_empty() && !self.done {
+            let (keys, next) =
+                list_page(self.namespace, self.cursor.as_deref(), self.options).await?;
+            self.page.extend(keys.into_iter().map(|key| key.name));
+            self.done = next.is_none();
+            self.cursor = next;
+        }
+        Ok(self.page.front())
+    }
+
+    async fn next(&mut self) -> Result<Option<String>, ApiFailure> {
+        self.peek().await?;
+        Ok(self.page.pop_front())
+    }
+}
+
+struct Page {
+    records: Vec<ArchiveRecord>,
+    /// The number of listed keys deleted before their value could be read.
+    deleted: u64,
+    next: Option<String>,
+}
+
+/// Lists a page of keys and reads their values. Keys deleted in between are left out.
+async fn read_page(
+    source: Namespace<'_>,
+    cursor: Option<&str>,
+    options: &TransferOptions,
+) -> Result<Page, ApiFailure> {
+    let (keys, next) = list_page(source, cursor, options).await?;
+    let records: Vec<Option<ArchiveRecord>> = stream::iter(keys)
+        .map(|key| async move {
+            let value = source
+                .client
+                .request(&ReadKey {
+                    account_identifier: source.account_identifier,
+                    namespace_identifier: source.namespace_identifier,
+                    key: &key.name,
+                })
+                .await;
+            match value {
+                Ok(value) => Ok(Some(ArchiveRecord {
+                    key: key.name,
+                    value,
+                    expiration: key.expiration.map(|expiration| expiration.timestamp()),
+                    metadata: key.metadata,
+                })),
+                Err(ApiFailure::Error(status, _)) if status == reqwest::StatusCode::NOT_FOUND => {
+                    Ok(None)
+                }
+                Err(e) => Err(e),
+            }
+        })
+        .buffered(options.read_concurrency.max(1))
+        .try_collect()
+        .await?;
+    let listed = records.len();
+    let records: Vec<ArchiveRecord> = records.into_iter().flatten().collect();
+    Ok(Page {
+        deleted: (listed - records.len()) as u64,
+        records,
+        next,
+    })
+}
+
+async fn write_records(
+    target: Namespace<'_>,
+    records: Vec<ArchiveRecord>,
+    options: &TransferOptions,
+    checkpoint: &mut Checkpoint,
+) -> Result<(), TransferError> {
+    let Some(last_key) = records.last().map(|record| record.key.clone()) else {
+        return Ok(());
+    };
+    let now = Utc::now().timestamp();
+    let (expiring, records): (Vec<_>, Vec<_>) = records
+        .into_iter()
+        .partition(|record| record.expires_too_soon(now));
+    checkpoint.skipped += expiring.len() as u64;
+    if records.is_empty() {
+        checkpoint.last_key = Some(last_key);
+        return Ok(());
+    }
+
+    let count = records.len() as u64;
+    let report = bulk::write_bulk(
+        target.client,
+        target.account_identifier,
+        target.namespace_identifier,
+        records.into_iter().map(ArchiveRecord::into_pair).collect(),
+        &options.bulk,
+    )
+    .await;
+    if !report.is_complete() {
+        return Err(TransferError::Incomplete(report));
+    }
+    checkpoint.keys += count;
+    checkpoint.last_key = Some(last_key);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::auth::Credentials;
+    use crate::framework::client::ClientConfig;
+    use crate::framework::Environment;
+    use mockito::{Matcher, Server};
+    use serde_json::json;
+
+    fn client(server: &Server) -> Client {
+        Client::new(
+            Credentials::UserAuthToken {
+                token: "dummy".into(),
+            },
+            ClientConfig::default(),
+            Environment::Custom(server.url()),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dump_pages_and_resumes() {
+        let keys = "/accounts/acc/storage/kv/namespaces/src/keys";
+        let values = "/accounts/acc/storage/kv/namespaces/src/values";
+
+        let mut server = Server::new_async().await;
+        let first_page = server
+            .mock("GET", keys)
+            .match_query(Matcher::Exact("limit=1".into()))
+            .with_body(
+                json!({
+                    "result": [{"name": "a", "metadata": {"owner": "me"}}],
+                    "result_info": {"count": 1, "cursor": "c1"},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+        let second_page = server
+            .mock("GET", keys)
+            .match_query(Matcher::Exact("limit=1&cursor=c1".into()))
+            .with_body(
+                json!({
+                    "result": [{"name": "b", "expiration": 1700000000}],
+                    "result_info": {"count": 1, "cursor": ""},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+        server
+            .mock("GET", format!("{values}/a").as_str())
+            .with_body("1")
+            .create();
+        server
+            .mock("GET", format!("{values}/b").as_str())
+            .with_body("2")
+            .create();
+
+        let client = client(&server);
+        let source = Namespace {
+            client: &client,
+            account_identifier: "acc",
+            namespace_identifier: "src",
+        };
+        let options = TransferOptions {
+            page_size: Some(1),
+            ..Default::default()
+        };
+
+        // Stop after the first page, as if interrupted, then resume from its checkpoint.
+        let mut archive = vec![];
+        let mut checkpoints = vec![];
+        let page = read_page(source, None, &options).await.unwrap();
+        for record in &page.records {
+            serde_json::to_writer(&mut archive, record).unwrap();
+            archive.push(b'\n');
+        }
+        let checkpoint = Checkpoint {
+            cursor: page.next,
+            keys: 1,
+            ..Default::default()
+        };
+        let done = dump(source, &mut archive, checkpoint, &options, |c| {
+            checkpoints.push(c.clone())
+        })
+        .await
+        .unwrap();
+
+        first_page.assert();
+        second_page.assert();
+        assert!(done.done);
+        assert_eq!(done.keys, 2);
+        assert_eq!(checkpoints, vec![done]);
+
+        let lines: Vec<ArchiveRecord> = archive
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ArchiveRecord {
+                    key: "a".into(),
+                    value: b"1".to_vec(),
+                    expiration: None,
+                    metadata: Some(json!({"owner": "me"})),
+                },
+                ArchiveRecord {
+                    key: "b".into(),
+                    value: b"2".to_vec(),
+                    expiration: Some(1700000000),
+                    metadata: None,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_restore_writes_base64_values_and_skips_expired() {
+        let mut server = Server::new_async().await;
+        let bulk = server
+            .mock("PUT", "/accounts/acc/storage/kv/namespaces/dst/bulk")
+            .match_body(Matcher::Json(json!([{
+                "key": "a",
+                "value": "aGVsbG8=",
+                "metadata": {"owner": "me"},
+                "base64": true
+            }])))
+            .with_body(
+                json!({
+                    "result": {"successful_key_count": 1, "unsuccessful_keys": []},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = client(&server);
+        let target = Namespace {
+            client: &client,
+            account_identifier: "acc",
+            namespace_identifier: "dst",
+        };
+        let archive = concat!(
+            r#"{"key":"a","value":"aGVsbG8=","metadata":{"owner":"me"}}"#,
+            "\n",
+            r#"{"key":"old","value":"","expiration":1}"#,
+            "\n",
+        );
+        let checkpoint = restore(
+            target,
+            archive.as_bytes(),
+            Checkpoint::default(),
+            &TransferOptions::default(),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        bulk.assert();
+        assert_eq!(checkpoint.keys, 1);
+        assert_eq!(checkpoint.skipped, 1);
+        assert_eq!(checkpoint.last_key.as_deref(), Some("old"));
+    }
+
+    #[tokio::test]
+    async fn test_restore_batches_by_size_and_resumes() {
+        let bulk = "/accounts/acc/storage/kv/namespaces/dst/bulk";
+        let success = json!({
+            "result": {"successful_key_count": 1, "unsuccessful_keys": []},
+            "success": true
+        })
+        .to_string();
+
+        let mut server = Server::new_async().await;
+        let first = server
+            .mock("PUT", bulk)
+            .match_body(Matcher::Json(
+                json!([{"key": "a", "value": "YQ==", "base64": true}]),
+            ))
+            .with_body(&success)
+            .create();
+        let second = server
+            .mock("PUT", bulk)
+            .match_body(Matcher::Json(
+                json!([{"key": "b", "value": "Yg==", "base64": true}]),
+            ))
+            .with_status(500)
+            .with_body(json!({"success": false, "errors": []}).to_string())
+            .expect_at_least(1)
+            .create();
+
+        let client = client(&server);
+        let target = Namespace {
+            client: &client,
+            account_identifier: "acc",
+            namespace_identifier: "dst",
+        };
+        let archive = concat!(
+            r#"{"key":"a","value":"YQ=="}"#,
+            "\n",
+            r#"{"key":"b","value":"Yg=="}"#,
+            "\n",
+        );
+        // Each record fills a batch on its own.
+        let options = TransferOptions {
+            max_batch_bytes: 1,
+            bulk: BulkOptions {
+                max_retries: 0,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut checkpoints = vec![];
+        let error = restore(
+            target,
+            archive.as_bytes(),
+            Checkpoint::default(),
+            &options,
+            |c| checkpoints.push(c.clone()),
+        )
+        .await
+        .unwrap_err();
+
+        first.assert();
+        second.assert();
+        assert!(matches!(error, TransferError::Incomplete(_)));
+        assert_eq!(checkpoints.len(), 1);
+        assert_eq!(checkpoints[0].last_key.as_deref(), Some("a"));
+
+        // Resuming from the last checkpoint only writes the records after it.
+        second.remove();
+        let resumed = server
+            .mock("PUT", bulk)
+            .match_body(Matcher::Json(
+                json!([{"key": "b", "value": "Yg==", "base64": true}]),
+            ))
+            .with_body(&success)
+            .create();
+        let done = restore(
+            target,
+            archive.as_bytes(),
+            checkpoints.pop().unwrap(),
+            &options,
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        resumed.assert();
+        first.assert();
+        assert!(done.done);
+        assert_eq!(done.keys, 2);
+        assert_eq!(done.last_key.as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn test_prune_compares_listings_page_by_page() {
+        let mut server = Server::new_async().await;
+        let pages = [
+            ("src", "", json!(["a", "c"]), "c1"),
+            ("src", "&cursor=c1", json!(["d"]), ""),
+            ("dst", "", json!(["a", "b"]), "c1"),
+            ("dst", "&cursor=c1", json!(["c", "e"]), ""),
+        ];
+        let mut mocks = vec![];
+        for (namespace, cursor, names, next) in pages {
+            let keys: Vec<_> = names
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|name| json!({"name": name}))
+                .collect();
+            mocks.push(
+                server
+                    .mock(
+                        "GET",
+                        format!("/accounts/acc/storage/kv/namespaces/{namespace}/keys").as_str(),
+                    )
+                    .match_query(Matcher::Exact(format!("limit=2{cursor}")))
+                    .with_body(
+                        json!({
+                            "result": keys,
+                            "result_info": {"cursor": next},
+                            "success": true
+                        })
+                        .to_string(),
+                    )
+                    .create(),
+            );
+        }
+        let delete = server
+            .mock("DELETE", "/accounts/acc/storage/kv/namespaces/dst/bulk")
+            .match_body(Matcher::Json(json!(["b", "e"])))
+            .with_body(
+                json!({
+                    "result": {"successful_key_count": 2, "unsuccessful_keys": []},
+                    "success": true
+                })
+                .to_string(),
+            )
+            .create();
+
+        let client = client(&server);
+        let namespace = |namespace_identifier| Namespace {
+            client: &client,
+            account_identifier: "acc",
+            namespace_identifier,
+        };
+        let options = TransferOptions {
+            page_size: Some(2),
+            ..Default::default()
+        };
+        let removed = prune(namespace("src"), namespace("dst"), &options)
+            .await
+            .unwrap();
+
+        for mock in mocks {
+            mock.assert();
+        }
+        delete.assert();
+        assert_eq!(removed, 2);
+    }
+}
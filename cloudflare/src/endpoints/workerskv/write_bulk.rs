@@ -13,6 +13,7 @@ use serde::{Deserialize, Serialize};
 /// If both are set, expiration_ttl is used and expiration is ignored.
 ///
 /// The entire request size must be 100 megabytes or less.
+/// Use [`super::bulk::write_bulk`] to write more pairs than fit in a single request.
 ///
 /// A `404` is returned if a write action is for a namespace ID the account doesn't have.
 ///
@@ -42,7 +43,6 @@ impl EndpointSpec for WriteBulk<'_> {
         if self.bulk_key_value_pairs.len() > 10_000 {
             panic!("Bulk write request must have 10,000 key-value pairs or less.");
         }
-        // TODO: The entire request size must be 100 megabytes or less.
         let body = serde_json::to_string(&self.bulk_key_value_pairs).unwrap();
         Some(RequestBody::Json(body))
     }
@@ -57,5 +57,7 @@ pub struct KeyValuePair {
     pub value: String,
     pub expiration: Option<i64>,
     pub expiration_ttl: Option<i64>,
+    /// Arbitrary JSON that is associated with the key.
+    pub metadata: Option<serde_json::Value>,
     pub base64: Option<bool>,
 }
@@ -84,6 +84,16 @@ pub struct WriteKeyBodyMetadata {
     pub metadata: serde_json::Value,
 }
 
+impl WriteKeyBodyMetadata {
+    /// Stores `value` with metadata of any type serializing to JSON.
+    pub fn new<M: Serialize>(value: Vec<u8>, metadata: &M) -> serde_json::Result<Self> {
+        Ok(WriteKeyBodyMetadata {
+            value,
+            metadata: serde_json::to_value(metadata)?,
+        })
+    }
+}
+
 impl MultipartBody for WriteKeyBodyMetadata {
     fn parts(&self) -> Vec<(String, MultipartPart)> {
         vec![
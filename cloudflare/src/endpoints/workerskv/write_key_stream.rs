@@ -0,0 +1,56 @@
+use super::write_key::WriteKeyParams;
+use crate::framework::endpoint::{serialize_query, EndpointSpec, Method, RequestBody, StreamBody};
+use crate::framework::response::ApiSuccess;
+
+/// Write a value identified by a key, streaming the value from a reader or a stream.
+///
+/// This is the streaming variant of [`WriteKey`](super::write_key::WriteKey): the value is not
+/// buffered in memory. Metadata can't be attached this way; use `WriteKey` for that.
+///
+/// The body is consumed by the request, so this endpoint can only be requested once.
+///
+/// Existing values, expirations, and metadata will be overwritten. If neither expiration nor
+/// expiration_ttl is specified, the key-value pair will never expire.
+/// If both are set, expiration_ttl is used and expiration is ignored.
+///
+/// <https://developers.cloudflare.com/api/resources/kv/subresources/namespaces/subresources/values/methods/update/>
+#[derive(Debug)]
+pub struct WriteKeyStream<'a> {
+    /// Identifier
+    pub account_identifier: &'a str,
+    /// Namespace identifier tag.
+    pub namespace_identifier: &'a str,
+    /// A key's name. The name may be at most 512 bytes.
+    /// All printable, non-whitespace characters are valid.
+    /// Use percent-encoding to define key names as part of a URL.
+    pub key: &'a str,
+    /// Parameters
+    pub params: WriteKeyParams,
+    /// The value to store.
+    pub body: StreamBody,
+}
+
+impl EndpointSpec for WriteKeyStream<'_> {
+    type JsonResponse = ();
+    type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+    fn method(&self) -> Method {
+        Method::PUT
+    }
+    fn path(&self) -> String {
+        format!(
+            "accounts/{}/storage/kv/namespaces/{}/values/{}",
+            self.account_identifier,
+            self.namespace_identifier,
+            super::url_encode_key(self.key)
+        )
+    }
+    #[inline]
+    fn query(&self) -> Option<String> {
+        serialize_query(&self.params)
+    }
+    #[inline]
+    fn body(&self) -> Option<RequestBody> {
+        Some(RequestBody::Stream(&self.body))
+    }
+}
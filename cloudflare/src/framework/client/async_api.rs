@@ -1,12 +1,13 @@
 use crate::framework::client::ClientConfig;
 use crate::framework::endpoint::{EndpointSpec, MultipartPart, RequestBody};
-use crate::framework::response::ResponseConverter;
+use crate::framework::response::{ByteStream, ResponseConverter};
 use crate::framework::{
     auth::{AuthClient, Credentials},
     response::ApiResponse,
     response::{ApiErrors, ApiFailure, ApiSuccess},
     Environment,
 };
+use futures_util::TryStreamExt;
 use std::borrow::Cow;
 use std::net::SocketAddr;
 
@@ -75,6 +76,10 @@ impl Client {
             .http_client
             .request(endpoint.method(), endpoint.url(&self.environment));
 
+        if let Some(headers) = endpoint.headers() {
+            request = request.headers(headers);
+        }
+
         if let Some(body) = endpoint.body() {
             match body {
                 RequestBody::Json(json) => {
@@ -93,10 +98,27 @@ impl Client {
                             MultipartPart::Bytes(bytes) => {
                                 form = form.part(name, reqwest::multipart::Part::bytes(bytes));
                             }
+                            MultipartPart::File {
+                                filename,
+                                content_type,
+                                bytes,
+                            } => {
+                                let part = reqwest::multipart::Part::bytes(bytes)
+                                    .file_name(filename)
+                                    .mime_str(&content_type)?;
+                                form = form.part(name, part);
+                            }
                         }
                     }
                     request = request.multipart(form);
                 }
+                RequestBody::Stream(stream) => {
+                    if let Some(length) = stream.length() {
+                        request = request.header(reqwest::header::CONTENT_LENGTH, length);
+                    }
+                    request =
+                        request.body(reqwest::Body::wrap_stream(stream.take()?.into_stream()));
+                }
             }
             // Reqwest::RequestBuilder::multipart sets the content type for us.
             match endpoint.content_type() {
@@ -112,7 +134,9 @@ impl Client {
 
         // The condition is necessary, even if a warning is present.
         // The constant is overridden in some cases.
-        if Endpoint::IS_RAW_BODY {
+        if Endpoint::IS_STREAMING_BODY {
+            map_api_response_stream::<Endpoint>(response).await
+        } else if Endpoint::IS_RAW_BODY {
             map_api_response_raw::<Endpoint>(response).await
         } else {
             map_api_response_json::<Endpoint>(response).await
@@ -132,8 +156,29 @@ where
 {
     let status = resp.status();
     if status.is_success() {
+        let headers = resp.headers().clone();
         let bytes = resp.bytes().await.map_err(ApiFailure::Invalid)?.to_vec();
-        Ok(Endpoint::ResponseType::from_raw(bytes))
+        Ok(Endpoint::ResponseType::from_raw_with_headers(
+            bytes, &headers,
+        ))
+    } else {
+        let parsed: Result<ApiErrors, reqwest::Error> = resp.json().await;
+        let errors = parsed.unwrap_or_default();
+        Err(ApiFailure::Error(status, errors))
+    }
+}
+
+async fn map_api_response_stream<Endpoint>(
+    resp: reqwest::Response,
+) -> Result<Endpoint::ResponseType, ApiFailure>
+where
+    Endpoint: EndpointSpec,
+    Endpoint::ResponseType: ResponseConverter<Endpoint::JsonResponse>,
+{
+    let status = resp.status();
+    if status.is_success() {
+        let stream = resp.bytes_stream().map_err(std::io::Error::other);
+        Ok(Endpoint::ResponseType::from_stream(ByteStream::new(stream)))
     } else {
         let parsed: Result<ApiErrors, reqwest::Error> = resp.json().await;
         let errors = parsed.unwrap_or_default();
@@ -168,10 +213,11 @@ mod tests {
     use super::*;
     use crate::framework::auth::Credentials;
     use crate::framework::client::ClientConfig;
-    use crate::framework::endpoint::RequestBody;
     use crate::framework::endpoint::{serialize_query, EndpointSpec};
+    use crate::framework::endpoint::{RequestBody, StreamBody};
     use crate::framework::response::{ApiFailure, ApiResult, ApiSuccess};
     use crate::framework::Environment;
+    use bytes::Bytes;
     use mockito::{Matcher, Server};
     use regex;
     use regex::Regex;
@@ -223,6 +269,25 @@ mod tests {
     }
     //endregion
 
+    //region Endpoint that streams its response.
+    #[derive(Debug)]
+    struct DummyStreamEndpoint;
+
+    impl EndpointSpec for DummyStreamEndpoint {
+        const IS_STREAMING_BODY: bool = true;
+        type JsonResponse = ();
+        type ResponseType = ByteStream;
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::GET
+        }
+
+        fn path(&self) -> String {
+            "/dummy/stream".into()
+        }
+    }
+    //endregion
+
     //region Endpoint that returns nothing.
     #[derive(Debug)]
     struct DummyNothingEndpoint;
@@ -286,6 +351,30 @@ mod tests {
     }
     //endregion
 
+    //region Endpoint that sends a streamed body.
+    #[derive(Debug)]
+    struct DummyStreamRequestEndpoint {
+        body: StreamBody,
+    }
+
+    impl EndpointSpec for DummyStreamRequestEndpoint {
+        type JsonResponse = ();
+        type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::PUT
+        }
+
+        fn path(&self) -> String {
+            "/dummy/stream".into()
+        }
+
+        fn body(&self) -> Option<RequestBody> {
+            Some(RequestBody::Stream(&self.body))
+        }
+    }
+    //endregion
+
     //region Endpoint that sends a multipart request.
     #[derive(Debug)]
     struct DummyMultipartEndpoint;
@@ -316,6 +405,43 @@ mod tests {
     }
     //endregion
 
+    //region Endpoint that sends a multipart request with a file part.
+    #[derive(Debug)]
+    struct DummyMultipartFileEndpoint;
+
+    impl EndpointSpec for DummyMultipartFileEndpoint {
+        type JsonResponse = ();
+        type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::POST
+        }
+
+        fn path(&self) -> String {
+            "/dummy/multipart".into()
+        }
+
+        fn body(&self) -> Option<RequestBody> {
+            Some(RequestBody::MultiPart(&DummyMultipartFile))
+        }
+    }
+
+    struct DummyMultipartFile;
+
+    impl crate::framework::endpoint::MultipartBody for DummyMultipartFile {
+        fn parts(&self) -> Vec<(String, MultipartPart)> {
+            vec![(
+                "worker.js".into(),
+                MultipartPart::File {
+                    filename: "worker.js".into(),
+                    content_type: "application/javascript+module".into(),
+                    bytes: b"export default {}".to_vec(),
+                },
+            )]
+        }
+    }
+    //endregion
+
     //region Endpoint that sends a request with query parameters.
     #[derive(Debug)]
     struct DummyJsonRequestWithQueryEndpoint;
@@ -410,6 +536,27 @@ mod tests {
         assert_eq!(response, raw_body);
     }
 
+    /// Test that the client can stream the response of an endpoint.
+    #[tokio::test]
+    async fn test_stream_endpoint_success() {
+        let raw_body = b"streamed content".to_vec();
+
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("GET", "/dummy/stream")
+            .with_status(200)
+            .with_header("content-type", "application/octet-stream")
+            .with_body(raw_body.clone())
+            .create();
+
+        let client = create_test_client(server.url());
+        let stream = client.request(&DummyStreamEndpoint).await.unwrap();
+        let chunks: Vec<_> = stream.try_collect().await.unwrap();
+
+        mock.assert();
+        assert_eq!(chunks.concat(), raw_body);
+    }
+
     /// Test that the client can handle an endpoint that returns an error.
     #[tokio::test]
     async fn test_endpoint_failure() {
@@ -526,6 +673,42 @@ mod tests {
         mock.assert();
     }
 
+    /// Test that the client can send a body from a stream and from a reader.
+    #[tokio::test]
+    async fn test_stream_body_success() {
+        let mut server = Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/dummy/stream")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(json!({"result": null, "success": true}).to_string())
+            .match_header("content-type", "application/octet-stream")
+            .match_body("streamed content")
+            .expect(2)
+            .create();
+
+        let client = create_test_client(server.url());
+        let chunks = vec![
+            Ok::<_, std::io::Error>(Bytes::from_static(b"streamed ")),
+            Ok(Bytes::from_static(b"content")),
+        ];
+        let from_stream = DummyStreamRequestEndpoint {
+            body: StreamBody::from_stream(futures_util::stream::iter(chunks)),
+        };
+        let from_reader = DummyStreamRequestEndpoint {
+            body: StreamBody::from_reader(std::io::Cursor::new(b"streamed content".to_vec()))
+                .with_length(16),
+        };
+        client.request(&from_stream).await.unwrap();
+        client.request(&from_reader).await.unwrap();
+
+        mock.assert();
+        assert_eq!(
+            client.request(&from_reader).await.unwrap_err(),
+            ApiFailure::BodyAlreadySent
+        );
+    }
+
     /// Test that the client can successfully send a multipart request.
     #[tokio::test]
     async fn test_multipart_body_success() {
@@ -567,6 +750,40 @@ mod tests {
         mock.assert();
     }
 
+    /// Test that the client sends the file name and MIME type of file parts.
+    #[tokio::test]
+    async fn test_multipart_file_body_success() {
+        let body = json!({
+            "result": null,
+            "result_info": null,
+            "messages": [],
+            "errors": [],
+            "success": true
+        });
+
+        let mut server = Server::new_async().await;
+
+        let mock = server
+            .mock("POST", "/dummy/multipart")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(body.to_string())
+            .match_request(|req| {
+                let body = req.body().unwrap().to_vec();
+                let body = String::from_utf8_lossy(&body);
+
+                body.contains(r#"name="worker.js"; filename="worker.js""#)
+                    && body.contains("Content-Type: application/javascript+module")
+                    && body.contains("export default {}")
+            })
+            .create();
+
+        let client = create_test_client(server.url());
+        let _ = client.request(&DummyMultipartFileEndpoint).await;
+
+        mock.assert();
+    }
+
     /// Test that the client can successfully send a request with query parameters.
     #[tokio::test]
     async fn test_query_parameters_success() {
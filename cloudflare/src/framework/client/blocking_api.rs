@@ -2,7 +2,7 @@ use crate::framework::auth::Credentials;
 use crate::framework::client::ClientConfig;
 use crate::framework::endpoint::{EndpointSpec, MultipartPart, RequestBody};
 use crate::framework::response::{
-    ApiErrors, ApiFailure, ApiResponse, ApiSuccess, ResponseConverter,
+    ApiErrors, ApiFailure, ApiResponse, ApiSuccess, ByteStream, ReadChunks, ResponseConverter,
 };
 use crate::framework::{auth::AuthClient, Environment};
 use reqwest::blocking::RequestBuilder;
@@ -65,6 +65,10 @@ impl HttpApiClient {
             .http_client
             .request(endpoint.method(), endpoint.url(&self.environment));
 
+        if let Some(headers) = endpoint.headers() {
+            request = request.headers(headers);
+        }
+
         if let Some(body) = endpoint.body() {
             match body {
                 RequestBody::Json(json) => {
@@ -84,10 +88,27 @@ impl HttpApiClient {
                                 form = form
                                     .part(name, reqwest::blocking::multipart::Part::bytes(bytes));
                             }
+                            MultipartPart::File {
+                                filename,
+                                content_type,
+                                bytes,
+                            } => {
+                                let part = reqwest::blocking::multipart::Part::bytes(bytes)
+                                    .file_name(filename)
+                                    .mime_str(&content_type)?;
+                                form = form.part(name, part);
+                            }
                         }
                     }
                     request = request.multipart(form);
                 }
+                RequestBody::Stream(stream) => {
+                    let reader = stream.take()?.into_reader();
+                    request = request.body(match stream.length() {
+                        Some(length) => reqwest::blocking::Body::sized(reader, length),
+                        None => reqwest::blocking::Body::new(reader),
+                    });
+                }
             }
             // Reqwest::RequestBuilder::multipart sets the content type for us.
             match endpoint.content_type() {
@@ -103,7 +124,9 @@ impl HttpApiClient {
 
         // The condition is necessary, even if a warning is present.
         // The constant is overridden in some cases.
-        if Endpoint::IS_RAW_BODY {
+        if Endpoint::IS_STREAMING_BODY {
+            map_api_response_stream::<Endpoint>(response)
+        } else if Endpoint::IS_RAW_BODY {
             let content_type = response
                 .headers()
                 .get(reqwest::header::CONTENT_TYPE)
@@ -139,8 +162,29 @@ where
 {
     let status = resp.status();
     if status.is_success() {
+        let headers = resp.headers().clone();
         let bytes = resp.bytes().map_err(ApiFailure::Invalid)?.to_vec();
-        Ok(Endpoint::ResponseType::from_raw(bytes))
+        Ok(Endpoint::ResponseType::from_raw_with_headers(
+            bytes, &headers,
+        ))
+    } else {
+        let parsed: Result<ApiErrors, reqwest::Error> = resp.json();
+        let errors = parsed.unwrap_or_default();
+        Err(ApiFailure::Error(status, errors))
+    }
+}
+
+fn map_api_response_stream<Endpoint>(
+    resp: reqwest::blocking::Response,
+) -> Result<Endpoint::ResponseType, ApiFailure>
+where
+    Endpoint: EndpointSpec,
+    Endpoint::ResponseType: ResponseConverter<Endpoint::JsonResponse>,
+{
+    let status = resp.status();
+    if status.is_success() {
+        let stream = futures_util::stream::iter(ReadChunks::new(resp));
+        Ok(Endpoint::ResponseType::from_stream(ByteStream::new(stream)))
     } else {
         let parsed: Result<ApiErrors, reqwest::Error> = resp.json();
         let errors = parsed.unwrap_or_default();
@@ -168,3 +212,122 @@ where
         Err(ApiFailure::Error(status, errors))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framework::endpoint::StreamBody;
+    use mockito::Server;
+    use serde_json::json;
+    use std::io::Write;
+
+    #[derive(Debug)]
+    struct DummyStreamEndpoint;
+
+    impl EndpointSpec for DummyStreamEndpoint {
+        const IS_STREAMING_BODY: bool = true;
+        type JsonResponse = ();
+        type ResponseType = ByteStream;
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::GET
+        }
+
+        fn path(&self) -> String {
+            "/dummy/stream".into()
+        }
+    }
+
+    #[derive(Debug)]
+    struct DummyStreamRequestEndpoint {
+        body: StreamBody,
+    }
+
+    impl EndpointSpec for DummyStreamRequestEndpoint {
+        type JsonResponse = ();
+        type ResponseType = ApiSuccess<Self::JsonResponse>;
+
+        fn method(&self) -> reqwest::Method {
+            reqwest::Method::PUT
+        }
+
+        fn path(&self) -> String {
+            "/dummy/stream".into()
+        }
+
+        fn body(&self) -> Option<RequestBody> {
+            Some(RequestBody::Stream(&self.body))
+        }
+    }
+
+    fn create_test_client(url: String) -> HttpApiClient {
+        let credentials = Credentials::UserAuthToken {
+            token: "dummy".into(),
+        };
+        HttpApiClient::new(
+            credentials,
+            ClientConfig::default(),
+            Environment::Custom(url),
+        )
+        .unwrap()
+    }
+
+    /// Test that a streamed response is read in chunks, across chunk boundaries.
+    #[test]
+    fn test_stream_endpoint_success() {
+        let raw_body: Vec<u8> = (0..150_000u32).map(|i| i as u8).collect();
+
+        let mut server = Server::new();
+        let mock = server
+            .mock("GET", "/dummy/stream")
+            .with_header("content-type", "application/octet-stream")
+            .with_body(raw_body.clone())
+            .create();
+
+        let client = create_test_client(server.url());
+        let chunks: Vec<_> = client
+            .request(&DummyStreamEndpoint)
+            .unwrap()
+            .into_blocking_iter()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        mock.assert();
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), raw_body);
+    }
+
+    /// Test that a body read from a `tokio::fs::File` can be sent, outside of any runtime, and
+    /// only once.
+    #[test]
+    fn test_async_read_body() {
+        let path = std::env::temp_dir().join("cloudflare-rs-blocking-stream-body");
+        std::fs::File::create(&path)
+            .unwrap()
+            .write_all(b"streamed content")
+            .unwrap();
+
+        let mut server = Server::new();
+        let mock = server
+            .mock("PUT", "/dummy/stream")
+            .with_header("content-type", "application/json")
+            .with_body(json!({"result": null, "success": true}).to_string())
+            .match_body("streamed content")
+            .create();
+
+        let client = create_test_client(server.url());
+        let endpoint = DummyStreamRequestEndpoint {
+            body: StreamBody::from_async_read(tokio::fs::File::from_std(
+                std::fs::File::open(&path).unwrap(),
+            )),
+        };
+        client.request(&endpoint).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        mock.assert();
+        assert_eq!(
+            client.request(&endpoint).unwrap_err(),
+            ApiFailure::BodyAlreadySent
+        );
+    }
+}
@@ -1,7 +1,12 @@
-use crate::framework::response::ApiResult;
+use crate::framework::response::{ApiFailure, ApiResult, ReadChunks};
 use crate::framework::Environment;
+use bytes::Bytes;
+use futures_util::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
 use serde::Serialize;
 use std::borrow::Cow;
+use std::fmt;
+use std::io::Read;
+use std::sync::Mutex;
 use url::Url;
 
 pub use http::Method;
@@ -12,11 +17,19 @@ pub enum RequestBody<'a> {
     Json(String),
     Raw(Vec<u8>),
     MultiPart(&'a dyn MultipartBody),
+    /// A body streamed to the API chunk by chunk. See [`StreamBody`].
+    Stream(&'a StreamBody),
 }
 
 pub enum MultipartPart {
     Text(String),
     Bytes(Vec<u8>),
+    /// A part carrying its own file name and MIME type (e.g. a Workers script module).
+    File {
+        filename: String,
+        content_type: String,
+        bytes: Vec<u8>,
+    },
 }
 
 /// Helper trait for endpoints that require a multipart body.
@@ -33,6 +46,170 @@ pub trait MultipartBody {
     fn parts(&self) -> Vec<(String, MultipartPart)>;
 }
 
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// A request body that is streamed to the API instead of being buffered in memory.
+///
+/// Both clients accept either kind of source: the async client reads blocking readers on tokio's
+/// blocking thread pool, and the blocking client drives streams on a single-threaded tokio runtime
+/// of its own, so `AsyncRead`s needing a reactor (e.g. a `tokio::fs::File`) work with it too.
+///
+/// The body is consumed when the request is sent, so an endpoint holding a `StreamBody` can only
+/// be requested once: sending it again fails with [`ApiFailure::BodyAlreadySent`].
+///
+/// [`ApiFailure::BodyAlreadySent`]: crate::framework::response::ApiFailure::BodyAlreadySent
+pub struct StreamBody {
+    source: Mutex<Option<StreamSource>>,
+    length: Option<u64>,
+}
+
+pub(crate) enum StreamSource {
+    Stream(BoxStream<'static, Result<Bytes, BoxError>>),
+    Reader(Box<dyn Read + Send>),
+}
+
+impl StreamBody {
+    /// Streams the body from a `Stream` of byte chunks.
+    pub fn from_stream<S, E>(stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, E>> + Send + 'static,
+        E: Into<BoxError> + 'static,
+    {
+        Self::new(StreamSource::Stream(stream.map_err(Into::into).boxed()))
+    }
+
+    /// Streams the body from an `AsyncRead`, e.g. a `tokio::fs::File`.
+    pub fn from_async_read<R>(reader: R) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + 'static,
+    {
+        Self::from_stream(tokio_util::io::ReaderStream::new(reader))
+    }
+
+    /// Streams the body from a blocking reader, e.g. a `std::fs::File`.
+    pub fn from_reader<R>(reader: R) -> Self
+    where
+        R: Read + Send + 'static,
+    {
+        Self::new(StreamSource::Reader(Box::new(reader)))
+    }
+
+    /// Sets the total length of the body in bytes, sent as the `Content-Length` header.
+    /// Without it, the body is sent with chunked transfer encoding.
+    pub fn with_length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    pub fn length(&self) -> Option<u64> {
+        self.length
+    }
+
+    fn new(source: StreamSource) -> Self {
+        StreamBody {
+            source: Mutex::new(Some(source)),
+            length: None,
+        }
+    }
+
+    /// Takes the source out of the body, leaving it empty.
+    pub(crate) fn take(&self) -> Result<StreamSource, ApiFailure> {
+        self.source
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+            .ok_or(ApiFailure::BodyAlreadySent)
+    }
+}
+
+/// A body already in memory, sent as a single chunk.
+impl From<Bytes> for StreamBody {
+    fn from(bytes: Bytes) -> Self {
+        let length = bytes.len() as u64;
+        Self::from_stream(stream::once(async { Ok::<_, BoxError>(bytes) })).with_length(length)
+    }
+}
+
+impl StreamSource {
+    pub(crate) fn into_stream(self) -> BoxStream<'static, Result<Bytes, BoxError>> {
+        match self {
+            StreamSource::Stream(stream) => stream,
+            #[cfg(not(target_arch = "wasm32"))]
+            StreamSource::Reader(reader) => {
+                // Each read may block, so it is moved off the async worker threads.
+                stream::unfold(Some(ReadChunks::new(reader)), |chunks| async move {
+                    let mut chunks = chunks?;
+                    match tokio::task::spawn_blocking(move || (chunks.next(), chunks)).await {
+                        Ok((chunk, chunks)) => Some((chunk?.map_err(Into::into), Some(chunks))),
+                        Err(e) => Some((Err(e.into()), None)),
+                    }
+                })
+                .boxed()
+            }
+            // There are no threads to block in wasm.
+            #[cfg(target_arch = "wasm32")]
+            StreamSource::Reader(reader) => stream::iter(ReadChunks::new(reader))
+                .map_err(Into::into)
+                .boxed(),
+        }
+    }
+
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub(crate) fn into_reader(self) -> Box<dyn Read + Send> {
+        match self {
+            StreamSource::Stream(stream) => Box::new(BlockingStreamReader {
+                runtime: None,
+                chunks: stream,
+                current: Bytes::new(),
+            }),
+            StreamSource::Reader(reader) => reader,
+        }
+    }
+}
+
+impl fmt::Debug for StreamBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StreamBody")
+            .field("length", &self.length)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reads a stream of chunks by blocking the current thread on each of them.
+///
+/// The stream is driven on a runtime of its own, started on the first read, since the blocking
+/// client doesn't run inside one.
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+struct BlockingStreamReader {
+    runtime: Option<tokio::runtime::Runtime>,
+    chunks: BoxStream<'static, Result<Bytes, BoxError>>,
+    current: Bytes,
+}
+
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+impl Read for BlockingStreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.current.is_empty() {
+            let runtime = match &mut self.runtime {
+                Some(runtime) => runtime,
+                None => self.runtime.insert(
+                    tokio::runtime::Builder::new_current_thread()
+                        .enable_all()
+                        .build()?,
+                ),
+            };
+            match runtime.block_on(self.chunks.next()) {
+                Some(Ok(chunk)) => self.current = chunk,
+                Some(Err(e)) => return Err(std::io::Error::other(e)),
+                None => return Ok(0),
+            }
+        }
+        let n = buf.len().min(self.current.len());
+        buf[..n].copy_from_slice(&self.current.split_to(n));
+        Ok(n)
+    }
+}
+
 pub mod spec {
     use super::*;
 
@@ -44,6 +221,12 @@ pub mod spec {
         /// If the body of the response is raw bytes (Vec<u8>), set this to `true`. Defaults to `false`.
         const IS_RAW_BODY: bool = false;
 
+        /// If the body of the response should be streamed instead of buffered, set this to
+        /// `true`. Defaults to `false`.
+        ///
+        /// For streaming endpoints, `ResponseType` should be `ByteStream`.
+        const IS_STREAMING_BODY: bool = false;
+
         /// The JSON response type for this endpoint, if any.
         ///
         /// For endpoints that return either raw bytes or nothing, this should be `()`.
@@ -77,6 +260,15 @@ pub mod spec {
             None
         }
 
+        /// Extra HTTP headers to send with the request, e.g. a jurisdiction selector.
+        /// Defaults to `None`.
+        ///
+        /// Implementors should inline this.
+        #[inline]
+        fn headers(&self) -> Option<http::HeaderMap> {
+            None
+        }
+
         /// Builds and returns a formatted full URL, including query, for the endpoint.
         ///
         /// Implementors should generally not override this.
@@ -95,6 +287,7 @@ pub mod spec {
                 Some(RequestBody::Json(_)) => Some(Cow::Borrowed("application/json")),
                 Some(RequestBody::Raw(_)) => Some(Cow::Borrowed("application/octet-stream")),
                 Some(RequestBody::MultiPart(_)) => Some(Cow::Borrowed("multipart/form-data")),
+                Some(RequestBody::Stream(_)) => Some(Cow::Borrowed("application/octet-stream")),
                 None => None,
             }
         }
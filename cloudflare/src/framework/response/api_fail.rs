@@ -26,6 +26,8 @@ impl Eq for ApiErrors {}
 pub enum ApiFailure {
     Error(reqwest::StatusCode, ApiErrors),
     Invalid(reqwest::Error),
+    /// The streaming request body of the endpoint was already sent by a previous request.
+    BodyAlreadySent,
 }
 
 impl Error for ApiFailure {}
@@ -37,6 +39,7 @@ impl PartialEq for ApiFailure {
             (ApiFailure::Error(status1, e1), ApiFailure::Error(status2, e2)) => {
                 status1 == status2 && e1 == e2
             }
+            (ApiFailure::BodyAlreadySent, ApiFailure::BodyAlreadySent) => true,
             _ => false,
         }
     }
@@ -57,6 +60,9 @@ impl fmt::Display for ApiFailure {
                 write!(f, "{output}")
             }
             ApiFailure::Invalid(err) => write!(f, "{err}"),
+            ApiFailure::BodyAlreadySent => {
+                write!(f, "a streaming request body can only be sent once")
+            }
         }
     }
 }
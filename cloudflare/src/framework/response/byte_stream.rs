@@ -0,0 +1,90 @@
+use bytes::Bytes;
+use futures_util::stream::{BoxStream, Stream};
+use std::fmt;
+use std::io::{self, Read};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// A response body that is streamed from the API chunk by chunk, instead of being buffered.
+///
+/// With the blocking client, every poll performs a blocking read of the underlying response.
+/// Use [`ByteStream::into_blocking_iter`] to consume it there.
+pub struct ByteStream {
+    inner: BoxStream<'static, io::Result<Bytes>>,
+}
+
+impl ByteStream {
+    pub(crate) fn new<S>(stream: S) -> Self
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        ByteStream {
+            inner: Box::pin(stream),
+        }
+    }
+
+    /// Iterates over the chunks of the body, blocking the current thread on each of them.
+    #[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+    pub fn into_blocking_iter(self) -> impl Iterator<Item = io::Result<Bytes>> {
+        futures_executor::block_on_stream(self)
+    }
+}
+
+impl Stream for ByteStream {
+    type Item = io::Result<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl fmt::Debug for ByteStream {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ByteStream").finish_non_exhaustive()
+    }
+}
+
+/// Adapts a blocking reader into an iterator of chunks, for use with `futures_util::stream::iter`.
+pub(crate) struct ReadChunks<R> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: Read> ReadChunks<R> {
+    const CHUNK_SIZE: usize = 64 * 1024;
+
+    pub(crate) fn new(reader: R) -> Self {
+        ReadChunks {
+            reader,
+            done: false,
+        }
+    }
+}
+
+impl<R: Read> Iterator for ReadChunks<R> {
+    type Item = io::Result<Bytes>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut buf = vec![0; Self::CHUNK_SIZE];
+        loop {
+            match self.reader.read(&mut buf) {
+                Ok(0) => {
+                    self.done = true;
+                    return None;
+                }
+                Ok(n) => {
+                    buf.truncate(n);
+                    return Some(Ok(Bytes::from(buf)));
+                }
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 mod api_fail;
+mod byte_stream;
 
 pub use api_fail::*;
+pub use byte_stream::ByteStream;
+pub(crate) use byte_stream::ReadChunks;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::value::Value as JsonValue;
@@ -28,10 +31,23 @@ impl<T> ApiResult for ApiSuccess<T> where T: ApiResult {}
 /// Some endpoints return nothing. That's OK.
 impl ApiResult for () {}
 
+/// Some endpoints only return a status message.
+impl ApiResult for String {}
+
+/// Some endpoints return either nothing or an empty object, with no documented fields.
+impl ApiResult for JsonValue {}
+
 /// A helper trait to avoid trait bounds issues in the clients.
 pub trait ResponseConverter<JsonResponse>: Sized {
     fn from_raw(bytes: Vec<u8>) -> Self;
+    /// Raw endpoints that need the response headers (e.g. an `expiration` header) override this.
+    fn from_raw_with_headers(bytes: Vec<u8>, _headers: &http::HeaderMap) -> Self {
+        Self::from_raw(bytes)
+    }
     fn from_json(api: ApiSuccess<JsonResponse>) -> Self;
+    fn from_stream(_stream: ByteStream) -> Self {
+        panic!("This endpoint does not return a stream")
+    }
 }
 // JSON endpoints
 impl<T> ResponseConverter<T> for ApiSuccess<T> {
@@ -51,6 +67,18 @@ impl ResponseConverter<()> for Vec<u8> {
         panic!("This endpoint does not return JSON")
     }
 }
+// Streaming endpoints
+impl ResponseConverter<()> for ByteStream {
+    fn from_raw(_bytes: Vec<u8>) -> Self {
+        panic!("This endpoint does not return raw bytes")
+    }
+    fn from_json(_api: ApiSuccess<()>) -> Self {
+        panic!("This endpoint does not return JSON")
+    }
+    fn from_stream(stream: ByteStream) -> Self {
+        stream
+    }
+}
 
 /// Note that ResponseInfo's `eq` implementation only compares `code` and `message`.
 /// It does NOT compare the `other` values.